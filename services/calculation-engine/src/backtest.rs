@@ -0,0 +1,216 @@
+use crate::signal_engine::Signal;
+use anyhow::Result;
+
+/// One completed round-trip trade.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Trade {
+    pub entry_index: usize,
+    pub exit_index: usize,
+    pub entry_price: f64,
+    pub exit_price: f64,
+    pub direction: Signal,
+    pub return_pct: f64,
+}
+
+/// Summary statistics produced by `BacktestEngine::run`.
+#[derive(Debug, Clone)]
+pub struct BacktestReport {
+    pub total_return: f64,
+    pub annualized_return: f64,
+    pub max_drawdown: f64,
+    pub win_rate: f64,
+    pub avg_win: f64,
+    pub avg_loss: f64,
+    pub profit_factor: f64,
+    pub equity_curve: Vec<f64>,
+    pub trades: Vec<Trade>,
+}
+
+struct OpenPosition {
+    direction: Signal,
+    entry_index: usize,
+    entry_price: f64,
+    entry_equity: f64,
+}
+
+/// Simulates a signal-driven strategy over historical OHLCV bars. A signal
+/// computed from bar `i` (e.g. from its close) is only acted on starting
+/// bar `i+1` - entries and exits always happen at the next bar's open, to
+/// avoid look-ahead bias. Commission/slippage is charged as a flat bps rate
+/// on notional at both entry and exit.
+pub struct BacktestEngine {
+    initial_capital: f64,
+    commission_bps: f64,
+}
+
+impl BacktestEngine {
+    pub fn new(initial_capital: f64, commission_bps: f64) -> Self {
+        Self { initial_capital, commission_bps }
+    }
+
+    /// Run the backtest. `signal_at(i)` is called once per bar except the
+    /// last (whose signal could not be acted on), with the
+    /// index of the bar whose close just completed - it may wrap a
+    /// `SignalEngine::evaluate` call over `closes[..=i]` (and the matching
+    /// `highs`/`lows` prefixes) or any other strategy logic.
+    pub fn run(&self, opens: &[f64], closes: &[f64], mut signal_at: impl FnMut(usize) -> Signal) -> Result<BacktestReport> {
+        if opens.len() != closes.len() {
+            return Err(anyhow::anyhow!("opens/closes must have equal length"));
+        }
+        if opens.is_empty() {
+            return Err(anyhow::anyhow!("backtest requires at least one bar"));
+        }
+
+        let len = closes.len();
+        let mut equity = self.initial_capital;
+        let mut equity_curve = Vec::with_capacity(len);
+        let mut trades = Vec::new();
+        let mut position: Option<OpenPosition> = None;
+
+        for i in 0..len {
+            if let Some(pos) = &position {
+                let direction_sign = if pos.direction == Signal::Long { 1.0 } else { -1.0 };
+                equity = pos.entry_equity * (1.0 + direction_sign * (closes[i] - pos.entry_price) / pos.entry_price);
+            }
+            equity_curve.push(equity);
+
+            if i + 1 >= len {
+                continue;
+            }
+
+            let signal = signal_at(i);
+            let next_open = opens[i + 1];
+            let commission = self.commission_bps / 10_000.0;
+
+            let should_close = matches!(&position, Some(pos) if signal != pos.direction);
+            if should_close {
+                if let Some(pos) = position.take() {
+                    let direction_sign = if pos.direction == Signal::Long { 1.0 } else { -1.0 };
+                    let gross_return = direction_sign * (next_open - pos.entry_price) / pos.entry_price;
+                    equity = pos.entry_equity * (1.0 + gross_return) * (1.0 - commission);
+                    trades.push(Trade {
+                        entry_index: pos.entry_index,
+                        exit_index: i + 1,
+                        entry_price: pos.entry_price,
+                        exit_price: next_open,
+                        direction: pos.direction,
+                        return_pct: gross_return - commission,
+                    });
+                }
+            }
+
+            if position.is_none() && matches!(signal, Signal::Long | Signal::Short) {
+                equity *= 1.0 - commission;
+                position = Some(OpenPosition {
+                    direction: signal,
+                    entry_index: i + 1,
+                    entry_price: next_open,
+                    entry_equity: equity,
+                });
+            }
+        }
+
+        Ok(self.build_report(equity_curve, trades))
+    }
+
+    fn build_report(&self, equity_curve: Vec<f64>, trades: Vec<Trade>) -> BacktestReport {
+        let final_equity = *equity_curve.last().unwrap_or(&self.initial_capital);
+        let total_return = (final_equity - self.initial_capital) / self.initial_capital;
+
+        let years = equity_curve.len() as f64 / 252.0;
+        let annualized_return = if years > 0.0 {
+            (1.0 + total_return).powf(1.0 / years) - 1.0
+        } else {
+            0.0
+        };
+
+        let mut max_drawdown = 0.0;
+        let mut peak = equity_curve.first().copied().unwrap_or(self.initial_capital);
+        for &value in &equity_curve {
+            if value > peak {
+                peak = value;
+            }
+            let drawdown = (peak - value) / peak;
+            if drawdown > max_drawdown {
+                max_drawdown = drawdown;
+            }
+        }
+
+        let wins: Vec<f64> = trades.iter().filter(|t| t.return_pct > 0.0).map(|t| t.return_pct).collect();
+        let losses: Vec<f64> = trades.iter().filter(|t| t.return_pct <= 0.0).map(|t| t.return_pct).collect();
+
+        let win_rate = if trades.is_empty() { 0.0 } else { wins.len() as f64 / trades.len() as f64 };
+        let avg_win = if wins.is_empty() { 0.0 } else { wins.iter().sum::<f64>() / wins.len() as f64 };
+        let avg_loss = if losses.is_empty() { 0.0 } else { losses.iter().sum::<f64>() / losses.len() as f64 };
+
+        let gross_profit: f64 = wins.iter().sum();
+        let gross_loss: f64 = losses.iter().map(|l| l.abs()).sum();
+        let profit_factor = if gross_loss > 0.0 { gross_profit / gross_loss } else { f64::INFINITY };
+
+        BacktestReport {
+            total_return,
+            annualized_return,
+            max_drawdown,
+            win_rate,
+            avg_win,
+            avg_loss,
+            profit_factor,
+            equity_curve,
+            trades,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_long_trade_profits_from_a_rising_open_to_open_move() {
+        let engine = BacktestEngine::new(10_000.0, 0.0);
+        let opens = vec![100.0, 100.0, 110.0, 110.0];
+        let closes = vec![100.0, 105.0, 110.0, 108.0];
+        let report = engine
+            .run(&opens, &closes, |i| if i == 0 { Signal::Long } else { Signal::Neutral })
+            .unwrap();
+
+        assert_eq!(report.trades.len(), 1);
+        assert!(report.trades[0].return_pct > 0.0);
+        assert!(report.total_return > 0.0);
+        assert_eq!(report.equity_curve.len(), closes.len());
+    }
+
+    #[test]
+    fn test_commission_is_charged_on_entry_and_exit() {
+        let with_commission = BacktestEngine::new(10_000.0, 100.0); // 1% per side
+        let no_commission = BacktestEngine::new(10_000.0, 0.0);
+        let opens = vec![100.0, 100.0, 100.0];
+        let closes = vec![100.0, 100.0, 100.0];
+        let signal_fn = |i: usize| if i == 0 { Signal::Long } else { Signal::Short };
+
+        let report_with = with_commission.run(&opens, &closes, signal_fn).unwrap();
+        let report_without = no_commission.run(&opens, &closes, signal_fn).unwrap();
+
+        assert!(report_with.total_return < report_without.total_return);
+    }
+
+    #[test]
+    fn test_flat_equity_curve_has_zero_drawdown_and_no_trades() {
+        let engine = BacktestEngine::new(5_000.0, 0.0);
+        let opens = vec![50.0, 50.0, 50.0];
+        let closes = vec![50.0, 50.0, 50.0];
+        let report = engine.run(&opens, &closes, |_| Signal::Neutral).unwrap();
+
+        assert!(report.trades.is_empty());
+        assert_eq!(report.max_drawdown, 0.0);
+        assert_eq!(report.total_return, 0.0);
+    }
+
+    #[test]
+    fn test_rejects_mismatched_lengths() {
+        let engine = BacktestEngine::new(1_000.0, 0.0);
+        let opens = vec![1.0, 2.0];
+        let closes = vec![1.0];
+        assert!(engine.run(&opens, &closes, |_| Signal::Neutral).is_err());
+    }
+}
@@ -2,8 +2,41 @@ use std::sync::atomic::{AtomicU64, AtomicU32, Ordering};
 use crossbeam::utils::CachePadded;
 use std::alloc::{alloc, dealloc, Layout};
 use std::ptr;
+use std::simd::{f64x4, num::SimdFloat};
+use crate::fixed_price::FixedPrice;
+
+/// Storage type for `CacheOptimizedMarketData`'s money fields: plain `f64`
+/// by default, or the checked-arithmetic `FixedPrice` under the
+/// `fixed-price` feature. Enabling the feature trades the struct's exact
+/// 64-byte cache-line size (FixedPrice is 16 bytes, twice an f64) for
+/// deterministic, overflow-safe money math.
+#[cfg(not(feature = "fixed-price"))]
+pub type Price = f64;
+#[cfg(feature = "fixed-price")]
+pub type Price = FixedPrice;
+
+#[cfg(not(feature = "fixed-price"))]
+fn to_price(value: f64) -> Price {
+    value
+}
+#[cfg(feature = "fixed-price")]
+fn to_price(value: f64) -> Price {
+    FixedPrice::from_f64(value).expect("market data price must be finite")
+}
 
-/// Cache-line aligned market data structure (64 bytes = 1 cache line)
+#[cfg(not(feature = "fixed-price"))]
+fn price_offset(value: Price, delta: f64) -> Price {
+    value + delta
+}
+#[cfg(feature = "fixed-price")]
+fn price_offset(value: Price, delta: f64) -> Price {
+    value
+        .checked_add(FixedPrice::from_f64(delta).expect("offset must be finite"))
+        .expect("market data price offset overflowed FixedPrice range")
+}
+
+/// Cache-line aligned market data structure (64 bytes = 1 cache line with
+/// the default `f64` price storage; larger under `fixed-price`).
 /// Optimized to fit exactly in one CPU cache line for maximum performance
 #[derive(Debug, Clone)]
 #[repr(C)]
@@ -11,14 +44,14 @@ use std::ptr;
 pub struct CacheOptimizedMarketData {
     // Core price data (32 bytes)
     pub symbol: [u8; 8],     // 8 bytes - symbol padded with zeros
-    pub price: f64,          // 8 bytes
+    pub price: Price,        // 8 bytes (16 under `fixed-price`)
     pub volume: u64,         // 8 bytes
     pub timestamp: u64,      // 8 bytes (nanoseconds since epoch)
 
     // Additional price levels (24 bytes)
-    pub bid: f64,            // 8 bytes
-    pub ask: f64,            // 8 bytes
-    pub high: f64,           // 8 bytes
+    pub bid: Price,          // 8 bytes (16 under `fixed-price`)
+    pub ask: Price,          // 8 bytes (16 under `fixed-price`)
+    pub high: Price,         // 8 bytes (16 under `fixed-price`)
 
     // Metadata and flags (8 bytes)
     pub low: f32,            // 4 bytes
@@ -33,14 +66,16 @@ impl CacheOptimizedMarketData {
         let len = std::cmp::min(bytes.len(), 8);
         symbol_bytes[..len].copy_from_slice(&bytes[..len]);
 
+        let stored_price = to_price(price);
+
         Self {
             symbol: symbol_bytes,
-            price,
+            price: stored_price,
             volume,
             timestamp: current_timestamp_nanos(),
-            bid: price - 0.01,
-            ask: price + 0.01,
-            high: price,
+            bid: price_offset(stored_price, -0.01),
+            ask: price_offset(stored_price, 0.01),
+            high: stored_price,
             low: price as f32,
             sequence: 0,
         }
@@ -218,6 +253,122 @@ impl CacheOptimizedPriceArray {
         prices.iter().fold(f64::NEG_INFINITY, |a, &b| a.max(b))
     }
 
+    /// Get volume slice for vectorized operations, mirroring `get_prices_slice`.
+    pub fn get_volumes_slice(&self, start: usize, len: usize) -> &[u64] {
+        if start + len > self.length {
+            panic!("Index out of bounds");
+        }
+
+        unsafe {
+            std::slice::from_raw_parts(self.volumes.add(start), len)
+        }
+    }
+
+    /// Explicit SIMD sum over a price slice, processing 4 f64 lanes per
+    /// iteration with a scalar tail loop for the remainder.
+    fn simd_sum(data: &[f64]) -> f64 {
+        let (chunks, remainder) = data.as_chunks::<4>();
+
+        let mut acc = f64x4::splat(0.0);
+        for chunk in chunks {
+            acc += f64x4::from_slice(chunk);
+        }
+        let mut sum = acc.reduce_sum();
+
+        for &x in remainder {
+            sum += x;
+        }
+        sum
+    }
+
+    /// Explicit `std::simd` average, replacing the auto-vectorization hope in
+    /// `calculate_avg_price_vectorized` with an explicit lane-width reduction.
+    pub fn calculate_avg_price_simd(&self, start: usize, len: usize) -> f64 {
+        let prices = self.get_prices_slice(start, len);
+        Self::simd_sum(prices) / len as f64
+    }
+
+    /// Fused dot product of prices and volumes (turnover = Σ price_i * volume_i)
+    /// in a single SIMD pass over both SoA arrays. Volumes are widened to f64
+    /// lane-by-lane since the SoA storage keeps them as `u64`.
+    pub fn sum_price_volume(&self, start: usize, len: usize) -> f64 {
+        let prices = self.get_prices_slice(start, len);
+        let volumes = self.get_volumes_slice(start, len);
+
+        let (price_chunks, price_remainder) = prices.as_chunks::<4>();
+        let (volume_chunks, volume_remainder) = volumes.as_chunks::<4>();
+
+        let mut acc = f64x4::splat(0.0);
+        for (p, v) in price_chunks.iter().zip(volume_chunks.iter()) {
+            let v_f64 = f64x4::from_array((*v).map(|x| x as f64));
+            acc += f64x4::from_slice(p) * v_f64;
+        }
+        let mut sum = acc.reduce_sum();
+
+        for (&p, &v) in price_remainder.iter().zip(volume_remainder.iter()) {
+            sum += p * v as f64;
+        }
+        sum
+    }
+
+    /// Min and max price in a single pass, using separate running SIMD
+    /// accumulators so the array is only traversed once.
+    pub fn min_max_price(&self, start: usize, len: usize) -> (f64, f64) {
+        let prices = self.get_prices_slice(start, len);
+        let (chunks, remainder) = prices.as_chunks::<4>();
+
+        let mut min_acc = f64x4::splat(f64::INFINITY);
+        let mut max_acc = f64x4::splat(f64::NEG_INFINITY);
+        for chunk in chunks {
+            let v = f64x4::from_slice(chunk);
+            min_acc = min_acc.simd_min(v);
+            max_acc = max_acc.simd_max(v);
+        }
+
+        let mut min = min_acc.reduce_min();
+        let mut max = max_acc.reduce_max();
+        for &x in remainder {
+            min = min.min(x);
+            max = max.max(x);
+        }
+
+        (min, max)
+    }
+
+    /// Mean and (population) variance of a price slice in one fused SIMD
+    /// traversal (accumulating Σx and Σx² together), then a z-score of the
+    /// last element of the slice against that distribution.
+    pub fn zscore_vectorized(&self, start: usize, len: usize) -> f64 {
+        let prices = self.get_prices_slice(start, len);
+        let (chunks, remainder) = prices.as_chunks::<4>();
+
+        let mut sum_acc = f64x4::splat(0.0);
+        let mut sum_sq_acc = f64x4::splat(0.0);
+        for chunk in chunks {
+            let v = f64x4::from_slice(chunk);
+            sum_acc += v;
+            sum_sq_acc += v * v;
+        }
+
+        let mut sum = sum_acc.reduce_sum();
+        let mut sum_sq = sum_sq_acc.reduce_sum();
+        for &x in remainder {
+            sum += x;
+            sum_sq += x * x;
+        }
+
+        let n = len as f64;
+        let mean = sum / n;
+        let variance = (sum_sq / n - mean * mean).max(0.0);
+        let std_dev = variance.sqrt();
+
+        if std_dev < 1e-12 {
+            return 0.0;
+        }
+
+        (prices[len - 1] - mean) / std_dev
+    }
+
     pub fn len(&self) -> usize {
         self.length
     }
@@ -301,6 +452,68 @@ impl CacheOptimizedMovingAverage {
     }
 }
 
+/// Volume/time-weighted rolling window (running VWAP) using a cache-friendly
+/// circular buffer layout, mirroring `CacheOptimizedMovingAverage` but
+/// weighting each value instead of averaging it equally.
+pub struct CacheOptimizedWeightedWindow {
+    values: Vec<f64>,
+    weights: Vec<f64>,
+    index: usize,
+    weighted_sum: f64,
+    weight_total: f64,
+    count: usize,
+    period: usize,
+    filled: bool,
+}
+
+impl CacheOptimizedWeightedWindow {
+    pub fn new(period: usize) -> Self {
+        Self {
+            values: vec![0.0; period],
+            weights: vec![0.0; period],
+            index: 0,
+            weighted_sum: 0.0,
+            weight_total: 0.0,
+            count: 0,
+            period,
+            filled: false,
+        }
+    }
+
+    /// Add a new (value, weight) pair - e.g. (price, volume) for a running
+    /// VWAP - and return the updated weighted average.
+    pub fn add(&mut self, value: f64, weight: f64) -> f64 {
+        let old_value = self.values[self.index];
+        let old_weight = self.weights[self.index];
+        self.values[self.index] = value;
+        self.weights[self.index] = weight;
+
+        if self.filled {
+            self.weighted_sum = self.weighted_sum - old_value * old_weight + value * weight;
+            self.weight_total = self.weight_total - old_weight + weight;
+        } else {
+            self.weighted_sum += value * weight;
+            self.weight_total += weight;
+            self.count += 1;
+            if self.count == self.period {
+                self.filled = true;
+            }
+        }
+
+        self.index = (self.index + 1) % self.period;
+
+        self.current_average()
+    }
+
+    pub fn current_average(&self) -> f64 {
+        if self.weight_total.abs() < 1e-12 {
+            0.0
+        } else {
+            self.weighted_sum / self.weight_total
+        }
+    }
+}
+
 /// Prefetch hint for cache optimization
 #[inline(always)]
 pub fn prefetch_read<T>(ptr: *const T) {
@@ -383,6 +596,45 @@ mod tests {
         assert!(avg_latency_ns < 100);
     }
 
+    #[test]
+    fn test_simd_kernels() {
+        let mut array = CacheOptimizedPriceArray::new(16);
+        for i in 0..10 {
+            let data = CacheOptimizedMarketData::new("AAPL", 100.0 + i as f64, 10);
+            array.push(&data);
+        }
+
+        let avg = array.calculate_avg_price_simd(0, 10);
+        assert!((avg - 104.5).abs() < 1e-9);
+
+        let turnover = array.sum_price_volume(0, 10);
+        let expected_turnover: f64 = (0..10).map(|i| (100.0 + i as f64) * 10.0).sum();
+        assert!((turnover - expected_turnover).abs() < 1e-9);
+
+        let (min, max) = array.min_max_price(0, 10);
+        assert_eq!(min, 100.0);
+        assert_eq!(max, 109.0);
+
+        let z = array.zscore_vectorized(0, 10);
+        assert!(z.is_finite());
+    }
+
+    #[test]
+    fn test_weighted_window_vwap() {
+        let mut window = CacheOptimizedWeightedWindow::new(3);
+
+        assert_eq!(window.add(10.0, 100.0), 10.0);
+        assert_eq!(window.add(20.0, 100.0), 15.0);
+
+        // Third sample fills the window: (10*100 + 20*100 + 30*200) / 400
+        let vwap = window.add(30.0, 200.0);
+        assert!((vwap - 22.5).abs() < 1e-9);
+
+        // Fourth sample evicts the first (10.0, 100.0): (20*100 + 30*200 + 40*100) / 400
+        let vwap = window.add(40.0, 100.0);
+        assert!((vwap - 30.0).abs() < 1e-9);
+    }
+
     #[test]
     fn test_cache_aligned_counters() {
         let counters = CacheAlignedCounters::new();
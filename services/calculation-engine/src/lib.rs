@@ -1,16 +1,27 @@
+#![feature(portable_simd)]
+
 pub mod financial;
 pub mod risk;
 pub mod technical;
 pub mod portfolio;
 pub mod cache;
 pub mod persistence;
+pub mod fixed_price;
+pub mod cache_optimized;
+pub mod tick_ingest;
+pub mod orderbook;
+pub mod signal_engine;
+pub mod backtest;
 
 pub use financial::FinancialCalculator;
 pub use risk::RiskCalculator;
 pub use technical::TechnicalIndicators;
 pub use portfolio::PortfolioAnalyzer;
 pub use cache::{L1Cache, UltraFastChannel, LockFreeQueue, MarketDataCached};
-pub use persistence::{UltraFastDB, PortfolioState, TimeSeriesPoint};
+pub use persistence::{UltraFastDB, PortfolioState, TimeSeriesPoint, PersistenceService, PersistenceHandle};
+pub use orderbook::{OrderBook, OrderBookManager};
+pub use signal_engine::{SignalEngine, Signal, IndicatorConfig, WeightedIndicator};
+pub use backtest::{BacktestEngine, BacktestReport, Trade};
 
 use std::ffi::{CStr, CString};
 use std::os::raw::{c_char, c_double, c_int};
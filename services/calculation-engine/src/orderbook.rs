@@ -1,4 +1,4 @@
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, HashMap};
 use std::cmp::Ordering;
 use ordered_float::OrderedFloat;
 use serde::{Deserialize, Serialize};
@@ -27,6 +27,27 @@ pub struct OrderBook {
     total_bid_volume: f64,
     total_ask_volume: f64,
     last_update_time: u64,
+
+    // Tick/lot/min-size/price-range constraints enforced on incoming orders.
+    market_config: MarketConfig,
+
+    // Last applied write-version per (side, price) level, for out-of-order
+    // dedup in `apply_level_update`.
+    level_write_versions: HashMap<(Side, OrderedFloat<f64>), u64>,
+
+    // Live orders by client order_id, tracking which level they contributed
+    // to and how much of their quantity remains - what `cancel_order`/
+    // `amend_order` use to adjust the correct level instead of an anonymous
+    // price/size pair.
+    orders: HashMap<String, (Side, OrderedFloat<f64>, f64, u64)>,
+
+    // Oracle-pegged orders by order_id, kept separate from `orders` since
+    // they reprice rather than rest at a fixed level.
+    pegs: HashMap<String, PeggedOrder>,
+
+    // Each peg's currently resting price, so `reprice_pegs` knows which
+    // level to remove its contribution from before re-inserting.
+    peg_prices: HashMap<String, OrderedFloat<f64>>,
 }
 
 /// Price level containing aggregated orders at a specific price
@@ -46,15 +67,57 @@ pub struct Order {
     pub price: f64,
     pub quantity: f64,
     pub timestamp: u64,
+    pub order_type: OrderType,
 }
 
 /// Order side
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum Side {
     Buy,
     Sell,
 }
 
+/// How an order should behave once it reaches the book: rest indefinitely,
+/// or trade immediately and then either discard or reject any remainder.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OrderType {
+    /// Rests on the book for any quantity not immediately matched.
+    Limit,
+    /// Matches what it can immediately; any remainder is discarded, not rested.
+    ImmediateOrCancel,
+    /// Matches only if the full quantity can be filled immediately against
+    /// current depth; otherwise the whole order is rejected, with no fills.
+    FillOrKill,
+}
+
+/// One execution produced by `OrderBook::match_order`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Fill {
+    pub price: f64,
+    pub quantity: f64,
+    /// Identifies the resting liquidity that was taken. Price levels are
+    /// still aggregated rather than per-order at this point, so this is a
+    /// synthetic id for the level rather than a real maker `order_id`; see
+    /// `OrderBook::cancel_order`/`amend_order` for genuine per-order tracking.
+    pub maker_order_id: String,
+    pub taker_side: Side,
+    pub timestamp: u64,
+}
+
+/// Estimated execution outcome of walking the book to fill `quantity` on one
+/// side, as produced by `OrderBook::simulate_fill`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FillEstimate {
+    pub avg_price: f64,
+    pub filled_quantity: f64,
+    pub worst_price: f64,
+    pub levels_consumed: usize,
+    pub slippage_bps: f64,
+    /// `true` when the book lacked enough depth to fill the full requested
+    /// quantity.
+    pub partial: bool,
+}
+
 /// Level 2 market data snapshot
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Level2Snapshot {
@@ -65,6 +128,123 @@ pub struct Level2Snapshot {
     pub sequence: u64,
 }
 
+/// Per-market trading constraints enforced by `OrderBook::add_order`, mirroring
+/// the tick/lot/min-size checks exchanges run before accepting an order. A
+/// field value of `0.0` (or `None` for `max_price`) means that constraint is
+/// not enforced, which is what `OrderBook::new` uses to preserve the
+/// unconstrained behavior existing callers rely on.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MarketConfig {
+    /// `price` must be an integer multiple of this, if non-zero.
+    pub tick_size: f64,
+    /// `quantity` must be an integer multiple of this, if non-zero.
+    pub lot_size: f64,
+    /// `quantity` must be at least this.
+    pub min_size: f64,
+    /// `price` must not exceed this, if set.
+    pub max_price: Option<f64>,
+}
+
+impl Default for MarketConfig {
+    fn default() -> Self {
+        Self {
+            tick_size: 0.0,
+            lot_size: 0.0,
+            min_size: 0.0,
+            max_price: None,
+        }
+    }
+}
+
+/// Tolerance for the tick/lot-size "integer multiple" checks, since `price /
+/// tick_size` and `quantity / lot_size` are floating point divisions.
+const SIZE_TOLERANCE: f64 = 1e-9;
+
+/// Errors from order book validation and operations. Supersedes the plain
+/// `String` errors `add_order`/`match_order` used before market-config
+/// enforcement was added; `Other` carries forward errors from book
+/// bookkeeping that aren't validation failures.
+#[derive(Debug, Clone, PartialEq)]
+pub enum OrderBookError {
+    /// `price` is not an integer multiple of the market's `tick_size`.
+    InvalidTickSize { price: f64, tick_size: f64 },
+    /// `quantity` is not an integer multiple of the market's `lot_size`.
+    InvalidLotSize { quantity: f64, lot_size: f64 },
+    /// `quantity` is below the market's `min_size`.
+    BelowMinimumSize { quantity: f64, min_size: f64 },
+    /// `price` exceeds the market's configured `max_price`.
+    InvalidPriceRange { price: f64, max_price: f64 },
+    /// A `FillOrKill` order's full quantity wasn't available at or better
+    /// than its price.
+    FillOrKillUnavailable { requested: f64, available: f64 },
+    /// `cancel_order`/`amend_order` referenced an `order_id` with no live
+    /// order on the book.
+    OrderNotFound { order_id: String },
+    /// Any other book error (e.g. from `remove_quantity`).
+    Other(String),
+}
+
+impl std::fmt::Display for OrderBookError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OrderBookError::InvalidTickSize { price, tick_size } => {
+                write!(f, "price {} is not a multiple of tick size {}", price, tick_size)
+            }
+            OrderBookError::InvalidLotSize { quantity, lot_size } => {
+                write!(f, "quantity {} is not a multiple of lot size {}", quantity, lot_size)
+            }
+            OrderBookError::BelowMinimumSize { quantity, min_size } => {
+                write!(f, "quantity {} is below minimum order size {}", quantity, min_size)
+            }
+            OrderBookError::InvalidPriceRange { price, max_price } => {
+                write!(f, "price {} exceeds maximum allowed price {}", price, max_price)
+            }
+            OrderBookError::FillOrKillUnavailable { requested, available } => {
+                write!(f, "FillOrKill order requires {} but only {} available", requested, available)
+            }
+            OrderBookError::OrderNotFound { order_id } => {
+                write!(f, "no live order with id {}", order_id)
+            }
+            OrderBookError::Other(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+impl std::error::Error for OrderBookError {}
+
+impl From<String> for OrderBookError {
+    fn from(message: String) -> Self {
+        OrderBookError::Other(message)
+    }
+}
+
+/// An order priced relative to a reference (oracle/mid) price rather than a
+/// fixed level. Repriced in bulk by `OrderBook::reprice_pegs` as the
+/// reference moves, instead of being resubmitted on every tick.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PeggedOrder {
+    pub order_id: String,
+    pub side: Side,
+    /// Added to the reference price to get this peg's effective price.
+    /// Negative pegs a bid below the reference; positive pegs an ask above it.
+    pub peg_offset: f64,
+    /// Clamps the effective price: a buy peg never prices above this, a sell
+    /// peg never prices below this.
+    pub limit_price: Option<f64>,
+    pub quantity: f64,
+}
+
+/// A single incremental price-level change, as produced by
+/// `OrderBook::diff_against` and consumed by `OrderBook::apply_level_update`.
+/// `new_size == 0.0` means the level was removed.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LevelUpdate {
+    pub side: Side,
+    pub price: f64,
+    pub new_size: f64,
+    pub write_version: u64,
+}
+
 /// Best bid/offer (Level 1 data)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BestBidOffer {
@@ -79,8 +259,16 @@ pub struct BestBidOffer {
 }
 
 impl OrderBook {
-    /// Create a new order book for a symbol
+    /// Create a new order book for a symbol, with no tick/lot/min-size
+    /// constraints on incoming orders.
     pub fn new(symbol: String) -> Self {
+        Self::new_with_config(symbol, MarketConfig::default())
+    }
+
+    /// Create a new order book for a symbol, enforcing `config`'s
+    /// tick/lot/min-size/price-range constraints on every order passed to
+    /// `add_order`/`match_order`.
+    pub fn new_with_config(symbol: String, config: MarketConfig) -> Self {
         Self {
             symbol,
             bids: BTreeMap::new(),
@@ -91,11 +279,51 @@ impl OrderBook {
             total_bid_volume: 0.0,
             total_ask_volume: 0.0,
             last_update_time: current_timestamp_nanos(),
+            market_config: config,
+            level_write_versions: HashMap::new(),
+            orders: HashMap::new(),
+            pegs: HashMap::new(),
+            peg_prices: HashMap::new(),
         }
     }
 
+    /// Check `price`/`quantity` against this book's `MarketConfig`, rejecting
+    /// sub-tick prices, sub-lot or below-minimum quantities, and out-of-range
+    /// prices before they can create a dust level in the book.
+    fn validate_order(&self, price: f64, quantity: f64) -> Result<(), OrderBookError> {
+        let config = &self.market_config;
+
+        if config.tick_size > 0.0 {
+            let ticks = price / config.tick_size;
+            if (ticks - ticks.round()).abs() > SIZE_TOLERANCE {
+                return Err(OrderBookError::InvalidTickSize { price, tick_size: config.tick_size });
+            }
+        }
+
+        if config.lot_size > 0.0 {
+            let lots = quantity / config.lot_size;
+            if (lots - lots.round()).abs() > SIZE_TOLERANCE {
+                return Err(OrderBookError::InvalidLotSize { quantity, lot_size: config.lot_size });
+            }
+        }
+
+        if quantity < config.min_size {
+            return Err(OrderBookError::BelowMinimumSize { quantity, min_size: config.min_size });
+        }
+
+        if let Some(max_price) = config.max_price {
+            if price > max_price {
+                return Err(OrderBookError::InvalidPriceRange { price, max_price });
+            }
+        }
+
+        Ok(())
+    }
+
     /// Add an order to the book - O(log n) complexity
-    pub fn add_order(&mut self, order: Order) -> Result<(), String> {
+    pub fn add_order(&mut self, order: Order) -> Result<(), OrderBookError> {
+        self.validate_order(order.price, order.quantity)?;
+
         self.sequence += 1;
         self.last_update_time = current_timestamp_nanos();
 
@@ -142,9 +370,238 @@ impl OrderBook {
             }
         }
 
+        self.orders.insert(order.order_id, (order.side, price_key, order.quantity, order.timestamp));
+
+        Ok(())
+    }
+
+    /// Cancel a previously added order by its client `order_id`, removing its
+    /// remaining quantity from the level it rests on (and the level itself,
+    /// if it empties). Unlike `remove_quantity`'s anonymous price/size pair,
+    /// this is what a FIX/exchange gateway uses, since a cancel message only
+    /// carries the order id.
+    pub fn cancel_order(&mut self, order_id: &str) -> Result<(), OrderBookError> {
+        let (side, price_key, quantity, _) = self
+            .orders
+            .remove(order_id)
+            .ok_or_else(|| OrderBookError::OrderNotFound { order_id: order_id.to_string() })?;
+
+        self.sequence += 1;
+        self.last_update_time = current_timestamp_nanos();
+
+        match side {
+            Side::Buy => {
+                if let Some(level) = self.bids.get_mut(&price_key) {
+                    level.size -= quantity;
+                    level.order_count = level.order_count.saturating_sub(1);
+                    self.total_bid_volume -= quantity;
+
+                    if level.size <= 0.0 {
+                        self.bids.remove(&price_key);
+                        if Some(price_key.into_inner()) == self.best_bid {
+                            self.best_bid = self.bids.keys().next_back().map(|p| p.into_inner());
+                        }
+                    }
+                }
+            }
+            Side::Sell => {
+                if let Some(level) = self.asks.get_mut(&price_key) {
+                    level.size -= quantity;
+                    level.order_count = level.order_count.saturating_sub(1);
+                    self.total_ask_volume -= quantity;
+
+                    if level.size <= 0.0 {
+                        self.asks.remove(&price_key);
+                        if Some(price_key.into_inner()) == self.best_ask {
+                            self.best_ask = self.asks.keys().next().map(|p| p.into_inner());
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Adjust a live order's remaining quantity by `order_id`, e.g. in
+    /// response to a FIX order-cancel/replace. `new_qty <= 0.0` is
+    /// equivalent to `cancel_order`.
+    pub fn amend_order(&mut self, order_id: &str, new_qty: f64) -> Result<(), OrderBookError> {
+        if new_qty <= 0.0 {
+            return self.cancel_order(order_id);
+        }
+
+        let (side, price_key, old_qty, timestamp) = *self
+            .orders
+            .get(order_id)
+            .ok_or_else(|| OrderBookError::OrderNotFound { order_id: order_id.to_string() })?;
+
+        let delta = new_qty - old_qty;
+        self.orders.insert(order_id.to_string(), (side, price_key, new_qty, timestamp));
+
+        self.sequence += 1;
+        self.last_update_time = current_timestamp_nanos();
+
+        match side {
+            Side::Buy => {
+                if let Some(level) = self.bids.get_mut(&price_key) {
+                    level.size += delta;
+                    self.total_bid_volume += delta;
+                }
+            }
+            Side::Sell => {
+                if let Some(level) = self.asks.get_mut(&price_key) {
+                    level.size += delta;
+                    self.total_ask_volume += delta;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Clamp a peg's computed `reference_price + peg_offset` by its
+    /// `limit_price`: a buy peg never prices above its limit, a sell peg
+    /// never prices below it.
+    fn clamp_peg_price(side: Side, price: f64, limit_price: Option<f64>) -> f64 {
+        match (side, limit_price) {
+            (Side::Buy, Some(limit)) => price.min(limit),
+            (Side::Sell, Some(limit)) => price.max(limit),
+            (_, None) => price,
+        }
+    }
+
+    /// Add an oracle-pegged order to the book, at its initial effective
+    /// price relative to `reference_price`. Call `reprice_pegs` as the
+    /// reference moves to keep it quoting at the right distance.
+    pub fn add_pegged_order(&mut self, peg: PeggedOrder, reference_price: f64) -> Result<(), OrderBookError> {
+        let price = Self::clamp_peg_price(peg.side, reference_price + peg.peg_offset, peg.limit_price);
+        self.validate_order(price, peg.quantity)?;
+
+        self.sequence += 1;
+        self.last_update_time = current_timestamp_nanos();
+
+        let price_key = OrderedFloat(price);
+        let timestamp = self.last_update_time;
+
+        match peg.side {
+            Side::Buy => {
+                let level = self.bids.entry(price_key).or_insert_with(|| PriceLevel {
+                    price,
+                    size: 0.0,
+                    order_count: 0,
+                    timestamp,
+                });
+                level.size += peg.quantity;
+                level.order_count += 1;
+                self.total_bid_volume += peg.quantity;
+                if self.best_bid.is_none() || price > self.best_bid.unwrap() {
+                    self.best_bid = Some(price);
+                }
+            }
+            Side::Sell => {
+                let level = self.asks.entry(price_key).or_insert_with(|| PriceLevel {
+                    price,
+                    size: 0.0,
+                    order_count: 0,
+                    timestamp,
+                });
+                level.size += peg.quantity;
+                level.order_count += 1;
+                self.total_ask_volume += peg.quantity;
+                if self.best_ask.is_none() || price < self.best_ask.unwrap() {
+                    self.best_ask = Some(price);
+                }
+            }
+        }
+
+        self.peg_prices.insert(peg.order_id.clone(), price_key);
+        self.pegs.insert(peg.order_id.clone(), peg);
+
         Ok(())
     }
 
+    /// Recompute every pegged order's effective price as `reference_price +
+    /// peg_offset` (clamped by `limit_price`), removing its old resting-level
+    /// contribution and re-inserting it at the new price. Pegs whose price
+    /// hasn't moved are left untouched. Updates `best_bid`/`best_ask` and
+    /// volumes as levels are vacated and refilled.
+    pub fn reprice_pegs(&mut self, reference_price: f64) {
+        let order_ids: Vec<String> = self.pegs.keys().cloned().collect();
+
+        for order_id in order_ids {
+            let peg = match self.pegs.get(&order_id) {
+                Some(peg) => peg.clone(),
+                None => continue,
+            };
+            let old_price_key = match self.peg_prices.get(&order_id) {
+                Some(&key) => key,
+                None => continue,
+            };
+
+            let new_price = Self::clamp_peg_price(peg.side, reference_price + peg.peg_offset, peg.limit_price);
+            let new_price_key = OrderedFloat(new_price);
+
+            if new_price_key == old_price_key {
+                continue;
+            }
+
+            self.sequence += 1;
+            self.last_update_time = current_timestamp_nanos();
+
+            match peg.side {
+                Side::Buy => {
+                    if let Some(level) = self.bids.get_mut(&old_price_key) {
+                        level.size -= peg.quantity;
+                        level.order_count = level.order_count.saturating_sub(1);
+                        self.total_bid_volume -= peg.quantity;
+                        if level.size <= 0.0 {
+                            self.bids.remove(&old_price_key);
+                        }
+                    }
+
+                    let timestamp = self.last_update_time;
+                    let level = self.bids.entry(new_price_key).or_insert_with(|| PriceLevel {
+                        price: new_price,
+                        size: 0.0,
+                        order_count: 0,
+                        timestamp,
+                    });
+                    level.size += peg.quantity;
+                    level.order_count += 1;
+                    self.total_bid_volume += peg.quantity;
+
+                    self.best_bid = self.bids.keys().next_back().map(|p| p.into_inner());
+                }
+                Side::Sell => {
+                    if let Some(level) = self.asks.get_mut(&old_price_key) {
+                        level.size -= peg.quantity;
+                        level.order_count = level.order_count.saturating_sub(1);
+                        self.total_ask_volume -= peg.quantity;
+                        if level.size <= 0.0 {
+                            self.asks.remove(&old_price_key);
+                        }
+                    }
+
+                    let timestamp = self.last_update_time;
+                    let level = self.asks.entry(new_price_key).or_insert_with(|| PriceLevel {
+                        price: new_price,
+                        size: 0.0,
+                        order_count: 0,
+                        timestamp,
+                    });
+                    level.size += peg.quantity;
+                    level.order_count += 1;
+                    self.total_ask_volume += peg.quantity;
+
+                    self.best_ask = self.asks.keys().next().map(|p| p.into_inner());
+                }
+            }
+
+            self.peg_prices.insert(order_id, new_price_key);
+        }
+    }
+
     /// Remove quantity from a price level - O(log n) complexity
     pub fn remove_quantity(&mut self, side: Side, price: f64, quantity: f64) -> Result<(), String> {
         self.sequence += 1;
@@ -200,6 +657,273 @@ impl OrderBook {
         Ok(())
     }
 
+    /// Decrement `qty_to_consume` out of the individual resting orders at
+    /// `(maker_side, price_key)`, oldest `timestamp` first (price-time
+    /// priority), removing any order whose remaining quantity hits zero.
+    /// Keeps `self.orders` in sync with the aggregated `PriceLevel.size`
+    /// that `match_order`'s fill loop already adjusts, so a later
+    /// `cancel_order`/`amend_order` on a partially-filled order doesn't act
+    /// on its stale pre-fill quantity.
+    ///
+    /// `self.orders` and `self.pegs` both contribute to the same aggregated
+    /// `PriceLevel.size`, so any quantity left over once every matching plain
+    /// order has been drained must have come from peg-sourced liquidity -
+    /// drain `self.pegs`/`self.peg_prices` for the remainder, or a
+    /// fully-filled peg would stay resting at its stale quantity and
+    /// reappear the next time `reprice_pegs` runs.
+    fn consume_resting_orders(&mut self, maker_side: Side, price_key: OrderedFloat<f64>, mut qty_to_consume: f64) {
+        if qty_to_consume <= 0.0 {
+            return;
+        }
+
+        let mut matching: Vec<(String, u64)> = self
+            .orders
+            .iter()
+            .filter(|(_, (side, key, _, _))| *side == maker_side && *key == price_key)
+            .map(|(order_id, (_, _, _, timestamp))| (order_id.clone(), *timestamp))
+            .collect();
+        matching.sort_by_key(|(_, timestamp)| *timestamp);
+
+        for (order_id, _) in matching {
+            if qty_to_consume <= 0.0 {
+                break;
+            }
+
+            if let Some(entry) = self.orders.get_mut(&order_id) {
+                let consumed = qty_to_consume.min(entry.2);
+                entry.2 -= consumed;
+                qty_to_consume -= consumed;
+
+                if entry.2 <= 0.0 {
+                    self.orders.remove(&order_id);
+                }
+            }
+        }
+
+        if qty_to_consume <= 0.0 {
+            return;
+        }
+
+        let matching_pegs: Vec<String> = self
+            .pegs
+            .iter()
+            .filter(|(order_id, peg)| peg.side == maker_side && self.peg_prices.get(*order_id) == Some(&price_key))
+            .map(|(order_id, _)| order_id.clone())
+            .collect();
+
+        for order_id in matching_pegs {
+            if qty_to_consume <= 0.0 {
+                break;
+            }
+
+            if let Some(peg) = self.pegs.get_mut(&order_id) {
+                let consumed = qty_to_consume.min(peg.quantity);
+                peg.quantity -= consumed;
+                qty_to_consume -= consumed;
+
+                if peg.quantity <= 0.0 {
+                    self.pegs.remove(&order_id);
+                    self.peg_prices.remove(&order_id);
+                }
+            }
+        }
+    }
+
+    /// Price-time-priority matching: cross `incoming` against resting
+    /// liquidity on the opposite side from the best price outward, then
+    /// either rest, discard, or reject any unfilled remainder depending on
+    /// `incoming.order_type`. A `FillOrKill` order is checked against
+    /// available depth before any fill is produced, so it either fills in
+    /// full or not at all.
+    pub fn match_order(&mut self, incoming: Order) -> Result<Vec<Fill>, OrderBookError> {
+        self.validate_order(incoming.price, incoming.quantity)?;
+
+        if incoming.order_type == OrderType::FillOrKill {
+            let available = self.available_depth(incoming.side, incoming.price);
+            if available < incoming.quantity {
+                return Err(OrderBookError::FillOrKillUnavailable {
+                    requested: incoming.quantity,
+                    available,
+                });
+            }
+        }
+
+        self.sequence += 1;
+        self.last_update_time = current_timestamp_nanos();
+
+        let mut fills = Vec::new();
+        let mut remaining = incoming.quantity;
+
+        match incoming.side {
+            Side::Buy => {
+                let crossed_prices: Vec<OrderedFloat<f64>> = self
+                    .asks
+                    .iter()
+                    .take_while(|(price, _)| incoming.price >= price.into_inner())
+                    .map(|(price, _)| *price)
+                    .collect();
+
+                for price_key in crossed_prices {
+                    if remaining <= 0.0 {
+                        break;
+                    }
+
+                    let level = match self.asks.get_mut(&price_key) {
+                        Some(level) => level,
+                        None => continue,
+                    };
+                    let fill_qty = remaining.min(level.size);
+
+                    fills.push(Fill {
+                        price: level.price,
+                        quantity: fill_qty,
+                        maker_order_id: format!("agg@{:.8}", level.price),
+                        taker_side: Side::Buy,
+                        timestamp: self.last_update_time,
+                    });
+
+                    level.size -= fill_qty;
+                    remaining -= fill_qty;
+                    self.total_ask_volume -= fill_qty;
+
+                    if level.size <= 0.0 {
+                        self.asks.remove(&price_key);
+                    }
+
+                    self.consume_resting_orders(Side::Sell, price_key, fill_qty);
+                }
+
+                self.best_ask = self.asks.keys().next().map(|p| p.into_inner());
+            }
+            Side::Sell => {
+                let crossed_prices: Vec<OrderedFloat<f64>> = self
+                    .bids
+                    .iter()
+                    .rev()
+                    .take_while(|(price, _)| incoming.price <= price.into_inner())
+                    .map(|(price, _)| *price)
+                    .collect();
+
+                for price_key in crossed_prices {
+                    if remaining <= 0.0 {
+                        break;
+                    }
+
+                    let level = match self.bids.get_mut(&price_key) {
+                        Some(level) => level,
+                        None => continue,
+                    };
+                    let fill_qty = remaining.min(level.size);
+
+                    fills.push(Fill {
+                        price: level.price,
+                        quantity: fill_qty,
+                        maker_order_id: format!("agg@{:.8}", level.price),
+                        taker_side: Side::Sell,
+                        timestamp: self.last_update_time,
+                    });
+
+                    level.size -= fill_qty;
+                    remaining -= fill_qty;
+                    self.total_bid_volume -= fill_qty;
+
+                    if level.size <= 0.0 {
+                        self.bids.remove(&price_key);
+                    }
+
+                    self.consume_resting_orders(Side::Buy, price_key, fill_qty);
+                }
+
+                self.best_bid = self.bids.keys().next_back().map(|p| p.into_inner());
+            }
+        }
+
+        if remaining > 0.0 {
+            match incoming.order_type {
+                OrderType::Limit => {
+                    let mut resting = incoming;
+                    resting.quantity = remaining;
+                    self.add_order(resting)?;
+                }
+                OrderType::ImmediateOrCancel | OrderType::FillOrKill => {
+                    // Unfilled remainder is discarded rather than rested.
+                }
+            }
+        }
+
+        Ok(fills)
+    }
+
+    /// Total resting quantity at or better than `price`, on the opposite
+    /// side of `side` - used to pre-check `FillOrKill` orders before any
+    /// fill is produced.
+    fn available_depth(&self, side: Side, price: f64) -> f64 {
+        match side {
+            Side::Buy => self
+                .asks
+                .iter()
+                .take_while(|(level_price, _)| price >= level_price.into_inner())
+                .map(|(_, level)| level.size)
+                .sum(),
+            Side::Sell => self
+                .bids
+                .iter()
+                .rev()
+                .take_while(|(level_price, _)| price <= level_price.into_inner())
+                .map(|(_, level)| level.size)
+                .sum(),
+        }
+    }
+
+    /// Walk the opposite side of the book (asks ascending for a buy, bids
+    /// descending for a sell), accumulating filled size level-by-level until
+    /// `quantity` is met, and return the resulting average/worst execution
+    /// price and slippage versus the current mid. If the book lacks enough
+    /// depth, the returned estimate is partial (`filled_quantity <
+    /// quantity`) rather than an error - this is a pre-trade what-if query,
+    /// not an order submission.
+    pub fn simulate_fill(&self, side: Side, quantity: f64) -> FillEstimate {
+        let levels: Vec<(f64, f64)> = match side {
+            Side::Buy => self.asks.iter().map(|(price, level)| (price.into_inner(), level.size)).collect(),
+            Side::Sell => self.bids.iter().rev().map(|(price, level)| (price.into_inner(), level.size)).collect(),
+        };
+
+        let mut remaining = quantity;
+        let mut filled_quantity = 0.0;
+        let mut weighted_sum = 0.0;
+        let mut worst_price = 0.0;
+        let mut levels_consumed = 0;
+
+        for (price, size) in levels {
+            if remaining <= 0.0 {
+                break;
+            }
+
+            let fill_qty = remaining.min(size);
+            weighted_sum += price * fill_qty;
+            filled_quantity += fill_qty;
+            remaining -= fill_qty;
+            worst_price = price;
+            levels_consumed += 1;
+        }
+
+        let avg_price = if filled_quantity > 0.0 { weighted_sum / filled_quantity } else { 0.0 };
+
+        let slippage_bps = match self.get_best_bid_offer().mid_price {
+            Some(mid) if mid > 0.0 && filled_quantity > 0.0 => ((avg_price - mid) / mid) * 10000.0,
+            _ => 0.0,
+        };
+
+        FillEstimate {
+            avg_price,
+            filled_quantity,
+            worst_price,
+            levels_consumed,
+            slippage_bps,
+            partial: filled_quantity < quantity,
+        }
+    }
+
     /// Get best bid and offer (Level 1 data) - O(1) complexity
     pub fn get_best_bid_offer(&self) -> BestBidOffer {
         let bid_level = self.best_bid.and_then(|price| {
@@ -325,10 +1049,87 @@ impl OrderBook {
         self.best_ask = None;
         self.total_bid_volume = 0.0;
         self.total_ask_volume = 0.0;
+        self.level_write_versions.clear();
+        self.orders.clear();
+        self.pegs.clear();
+        self.peg_prices.clear();
         self.sequence += 1;
         self.last_update_time = current_timestamp_nanos();
     }
 
+    /// Apply a single incremental level update from a feed keyed by a
+    /// monotonic `write_version`. Updates whose `write_version` is not
+    /// strictly greater than the last seen version for that `(side, price)`
+    /// level are ignored as out-of-order duplicates. `new_size <= 0.0` removes
+    /// the level entirely; otherwise the level is inserted or resized.
+    /// Returns whether the update was applied.
+    pub fn apply_level_update(&mut self, side: Side, price: f64, new_size: f64, write_version: u64) -> bool {
+        let version_key = (side, OrderedFloat(price));
+        if let Some(&last_version) = self.level_write_versions.get(&version_key) {
+            if write_version <= last_version {
+                return false;
+            }
+        }
+        self.level_write_versions.insert(version_key, write_version);
+
+        let price_key = OrderedFloat(price);
+        self.sequence += 1;
+        self.last_update_time = current_timestamp_nanos();
+
+        match side {
+            Side::Buy => {
+                if new_size <= 0.0 {
+                    if let Some(level) = self.bids.remove(&price_key) {
+                        self.total_bid_volume -= level.size;
+                    }
+                } else {
+                    let order_count = self.bids.get(&price_key).map_or(1, |l| l.order_count);
+                    let old_size = self.bids.get(&price_key).map_or(0.0, |l| l.size);
+                    self.bids.insert(price_key, PriceLevel {
+                        price,
+                        size: new_size,
+                        order_count,
+                        timestamp: self.last_update_time,
+                    });
+                    self.total_bid_volume += new_size - old_size;
+                }
+                self.best_bid = self.bids.keys().next_back().map(|p| p.into_inner());
+            }
+            Side::Sell => {
+                if new_size <= 0.0 {
+                    if let Some(level) = self.asks.remove(&price_key) {
+                        self.total_ask_volume -= level.size;
+                    }
+                } else {
+                    let order_count = self.asks.get(&price_key).map_or(1, |l| l.order_count);
+                    let old_size = self.asks.get(&price_key).map_or(0.0, |l| l.size);
+                    self.asks.insert(price_key, PriceLevel {
+                        price,
+                        size: new_size,
+                        order_count,
+                        timestamp: self.last_update_time,
+                    });
+                    self.total_ask_volume += new_size - old_size;
+                }
+                self.best_ask = self.asks.keys().next().map(|p| p.into_inner());
+            }
+        }
+
+        true
+    }
+
+    /// Minimal diff between `previous` (an earlier Level 2 snapshot) and this
+    /// book's current state: one `LevelUpdate` per price level that was
+    /// added, changed size, or was removed since `previous` was taken. Each
+    /// update is stamped with this book's current sequence number, suitable
+    /// for passing straight to a downstream `apply_level_update`.
+    pub fn diff_against(&self, previous: &Level2Snapshot) -> Vec<LevelUpdate> {
+        let mut updates = Vec::new();
+        diff_levels(&previous.bids, &self.bids, Side::Buy, self.sequence, &mut updates);
+        diff_levels(&previous.asks, &self.asks, Side::Sell, self.sequence, &mut updates);
+        updates
+    }
+
     /// Get the symbol
     pub fn symbol(&self) -> &str {
         &self.symbol
@@ -382,15 +1183,9 @@ impl OrderBookManager {
         self.books.keys().cloned().collect()
     }
 
-    /// Add order to appropriate book
-    pub fn add_order(&mut self, order: Order) -> Result<(), String> {
-        let symbol = match &order.side {
-            Side::Buy | Side::Sell => {
-                // Extract symbol from order context or pass it separately
-                // For now, we'll need the symbol to be provided
-                return Err("Symbol must be provided with order".to_string());
-            }
-        };
+    /// Add an order to the named symbol's book
+    pub fn add_order(&mut self, symbol: &str, order: Order) -> Result<(), OrderBookError> {
+        self.get_or_create_book(symbol).add_order(order)
     }
 
     /// Process market data update
@@ -408,6 +1203,7 @@ impl OrderBookManager {
                 price: bid.price,
                 quantity: bid.size,
                 timestamp: bid.timestamp,
+                order_type: OrderType::Limit,
             };
             let _ = book.add_order(order);
         }
@@ -420,12 +1216,45 @@ impl OrderBookManager {
                 price: ask.price,
                 quantity: ask.size,
                 timestamp: ask.timestamp,
+                order_type: OrderType::Limit,
             };
             let _ = book.add_order(order);
         }
     }
 }
 
+/// Compare one side of a prior `Level2Snapshot` against the book's current
+/// levels for that side, appending a `LevelUpdate` for every price that is
+/// new, size-changed, or no longer present.
+fn diff_levels(
+    previous: &[PriceLevel],
+    current: &BTreeMap<OrderedFloat<f64>, PriceLevel>,
+    side: Side,
+    write_version: u64,
+    updates: &mut Vec<LevelUpdate>,
+) {
+    let previous_sizes: HashMap<OrderedFloat<f64>, f64> = previous
+        .iter()
+        .map(|level| (OrderedFloat(level.price), level.size))
+        .collect();
+
+    for (price_key, level) in current.iter() {
+        let changed = match previous_sizes.get(price_key) {
+            Some(&old_size) => (old_size - level.size).abs() > SIZE_TOLERANCE,
+            None => true,
+        };
+        if changed {
+            updates.push(LevelUpdate { side, price: level.price, new_size: level.size, write_version });
+        }
+    }
+
+    for price_key in previous_sizes.keys() {
+        if !current.contains_key(price_key) {
+            updates.push(LevelUpdate { side, price: price_key.into_inner(), new_size: 0.0, write_version });
+        }
+    }
+}
+
 /// Get current timestamp in nanoseconds
 fn current_timestamp_nanos() -> u64 {
     SystemTime::now()
@@ -450,6 +1279,7 @@ mod tests {
             price: 150.00,
             quantity: 100.0,
             timestamp: current_timestamp_nanos(),
+            order_type: OrderType::Limit,
         };
 
         let sell_order = Order {
@@ -458,6 +1288,7 @@ mod tests {
             price: 150.05,
             quantity: 200.0,
             timestamp: current_timestamp_nanos(),
+            order_type: OrderType::Limit,
         };
 
         book.add_order(buy_order).unwrap();
@@ -486,6 +1317,7 @@ mod tests {
                 price: 150.00 - (i as f64) * 0.01,
                 quantity: 100.0,
                 timestamp: current_timestamp_nanos(),
+                order_type: OrderType::Limit,
             };
 
             let sell_order = Order {
@@ -494,6 +1326,7 @@ mod tests {
                 price: 150.05 + (i as f64) * 0.01,
                 quantity: 100.0,
                 timestamp: current_timestamp_nanos(),
+                order_type: OrderType::Limit,
             };
 
             book.add_order(buy_order).unwrap();
@@ -527,6 +1360,7 @@ mod tests {
             price: 100.00,
             quantity: 100.0,
             timestamp: current_timestamp_nanos(),
+            order_type: OrderType::Limit,
         };
 
         let sell_order = Order {
@@ -535,6 +1369,7 @@ mod tests {
             price: 100.10,
             quantity: 100.0,
             timestamp: current_timestamp_nanos(),
+            order_type: OrderType::Limit,
         };
 
         book.add_order(buy_order).unwrap();
@@ -547,4 +1382,577 @@ mod tests {
         let spread_bps = stats.spread_bps.unwrap();
         assert!((spread_bps - 10.0).abs() < 1.0); // Within 1 bps tolerance
     }
+
+    #[test]
+    fn test_match_order_crosses_resting_liquidity() {
+        let mut book = OrderBook::new("AAPL".to_string());
+
+        book.add_order(Order {
+            order_id: "ask1".to_string(),
+            side: Side::Sell,
+            price: 100.00,
+            quantity: 50.0,
+            timestamp: current_timestamp_nanos(),
+            order_type: OrderType::Limit,
+        })
+        .unwrap();
+        book.add_order(Order {
+            order_id: "ask2".to_string(),
+            side: Side::Sell,
+            price: 100.10,
+            quantity: 50.0,
+            timestamp: current_timestamp_nanos(),
+            order_type: OrderType::Limit,
+        })
+        .unwrap();
+
+        let taker = Order {
+            order_id: "buy1".to_string(),
+            side: Side::Buy,
+            price: 100.10,
+            quantity: 75.0,
+            timestamp: current_timestamp_nanos(),
+            order_type: OrderType::Limit,
+        };
+
+        let fills = book.match_order(taker).unwrap();
+
+        assert_eq!(fills.len(), 2);
+        assert_eq!(fills[0].price, 100.00);
+        assert_eq!(fills[0].quantity, 50.0);
+        assert_eq!(fills[1].price, 100.10);
+        assert_eq!(fills[1].quantity, 25.0);
+
+        // The first ask level is fully consumed and removed; the second has
+        // 25 left resting, and the incoming order fully filled (no rest).
+        let bbo = book.get_best_bid_offer();
+        assert_eq!(bbo.ask_price, Some(100.10));
+        assert_eq!(bbo.ask_size, Some(25.0));
+        assert_eq!(bbo.bid_price, None);
+    }
+
+    #[test]
+    fn test_fill_or_kill_rejected_when_depth_insufficient() {
+        let mut book = OrderBook::new("AAPL".to_string());
+
+        book.add_order(Order {
+            order_id: "ask1".to_string(),
+            side: Side::Sell,
+            price: 100.00,
+            quantity: 10.0,
+            timestamp: current_timestamp_nanos(),
+            order_type: OrderType::Limit,
+        })
+        .unwrap();
+
+        let taker = Order {
+            order_id: "buy1".to_string(),
+            side: Side::Buy,
+            price: 100.00,
+            quantity: 50.0,
+            timestamp: current_timestamp_nanos(),
+            order_type: OrderType::FillOrKill,
+        };
+
+        assert!(book.match_order(taker).is_err());
+        // Nothing should have been consumed by the rejected FOK order.
+        assert_eq!(book.get_best_bid_offer().ask_size, Some(10.0));
+    }
+
+    #[test]
+    fn test_immediate_or_cancel_discards_remainder() {
+        let mut book = OrderBook::new("AAPL".to_string());
+
+        book.add_order(Order {
+            order_id: "ask1".to_string(),
+            side: Side::Sell,
+            price: 100.00,
+            quantity: 10.0,
+            timestamp: current_timestamp_nanos(),
+            order_type: OrderType::Limit,
+        })
+        .unwrap();
+
+        let taker = Order {
+            order_id: "buy1".to_string(),
+            side: Side::Buy,
+            price: 100.00,
+            quantity: 50.0,
+            timestamp: current_timestamp_nanos(),
+            order_type: OrderType::ImmediateOrCancel,
+        };
+
+        let fills = book.match_order(taker).unwrap();
+        assert_eq!(fills.len(), 1);
+        assert_eq!(fills[0].quantity, 10.0);
+
+        // The unfilled 40 units must not rest on the book.
+        let bbo = book.get_best_bid_offer();
+        assert_eq!(bbo.ask_price, None);
+        assert_eq!(bbo.bid_price, None);
+    }
+
+    #[test]
+    fn test_add_order_rejects_sub_tick_price() {
+        let config = MarketConfig { tick_size: 0.05, lot_size: 0.0, min_size: 0.0, max_price: None };
+        let mut book = OrderBook::new_with_config("AAPL".to_string(), config);
+
+        let order = Order {
+            order_id: "buy1".to_string(),
+            side: Side::Buy,
+            price: 100.02,
+            quantity: 100.0,
+            timestamp: current_timestamp_nanos(),
+            order_type: OrderType::Limit,
+        };
+
+        assert_eq!(
+            book.add_order(order),
+            Err(OrderBookError::InvalidTickSize { price: 100.02, tick_size: 0.05 })
+        );
+    }
+
+    #[test]
+    fn test_add_order_rejects_sub_lot_quantity_and_below_minimum() {
+        let config = MarketConfig { tick_size: 0.0, lot_size: 10.0, min_size: 20.0, max_price: None };
+        let mut book = OrderBook::new_with_config("AAPL".to_string(), config);
+
+        let bad_lot = Order {
+            order_id: "buy1".to_string(),
+            side: Side::Buy,
+            price: 100.00,
+            quantity: 25.0,
+            timestamp: current_timestamp_nanos(),
+            order_type: OrderType::Limit,
+        };
+        assert_eq!(
+            book.add_order(bad_lot),
+            Err(OrderBookError::InvalidLotSize { quantity: 25.0, lot_size: 10.0 })
+        );
+
+        let below_min = Order {
+            order_id: "buy2".to_string(),
+            side: Side::Buy,
+            price: 100.00,
+            quantity: 10.0,
+            timestamp: current_timestamp_nanos(),
+            order_type: OrderType::Limit,
+        };
+        assert_eq!(
+            book.add_order(below_min),
+            Err(OrderBookError::BelowMinimumSize { quantity: 10.0, min_size: 20.0 })
+        );
+    }
+
+    #[test]
+    fn test_add_order_rejects_price_above_max() {
+        let config = MarketConfig { tick_size: 0.0, lot_size: 0.0, min_size: 0.0, max_price: Some(100.0) };
+        let mut book = OrderBook::new_with_config("AAPL".to_string(), config);
+
+        let order = Order {
+            order_id: "buy1".to_string(),
+            side: Side::Buy,
+            price: 150.0,
+            quantity: 1.0,
+            timestamp: current_timestamp_nanos(),
+            order_type: OrderType::Limit,
+        };
+
+        assert_eq!(
+            book.add_order(order),
+            Err(OrderBookError::InvalidPriceRange { price: 150.0, max_price: 100.0 })
+        );
+    }
+
+    #[test]
+    fn test_unconstrained_book_accepts_any_tick_and_size() {
+        // OrderBook::new's default MarketConfig must not reject anything
+        // the pre-existing tests already rely on.
+        let mut book = OrderBook::new("AAPL".to_string());
+
+        let order = Order {
+            order_id: "buy1".to_string(),
+            side: Side::Buy,
+            price: 100.017,
+            quantity: 0.3,
+            timestamp: current_timestamp_nanos(),
+            order_type: OrderType::Limit,
+        };
+
+        assert!(book.add_order(order).is_ok());
+    }
+
+    #[test]
+    fn test_apply_level_update_inserts_resizes_and_removes() {
+        let mut book = OrderBook::new("AAPL".to_string());
+
+        assert!(book.apply_level_update(Side::Buy, 100.00, 50.0, 1));
+        assert_eq!(book.get_best_bid_offer().bid_size, Some(50.0));
+
+        assert!(book.apply_level_update(Side::Buy, 100.00, 75.0, 2));
+        assert_eq!(book.get_best_bid_offer().bid_size, Some(75.0));
+        assert_eq!(book.get_statistics().total_bid_volume, 75.0);
+
+        assert!(book.apply_level_update(Side::Buy, 100.00, 0.0, 3));
+        assert_eq!(book.get_best_bid_offer().bid_price, None);
+        assert_eq!(book.get_statistics().total_bid_volume, 0.0);
+    }
+
+    #[test]
+    fn test_apply_level_update_ignores_out_of_order_write_version() {
+        let mut book = OrderBook::new("AAPL".to_string());
+
+        assert!(book.apply_level_update(Side::Sell, 100.00, 50.0, 5));
+        // Same or older write_version must be ignored as a stale duplicate.
+        assert!(!book.apply_level_update(Side::Sell, 100.00, 999.0, 5));
+        assert!(!book.apply_level_update(Side::Sell, 100.00, 999.0, 3));
+        assert_eq!(book.get_best_bid_offer().ask_size, Some(50.0));
+
+        assert!(book.apply_level_update(Side::Sell, 100.00, 60.0, 6));
+        assert_eq!(book.get_best_bid_offer().ask_size, Some(60.0));
+    }
+
+    #[test]
+    fn test_diff_against_finds_added_changed_and_removed_levels() {
+        let mut book = OrderBook::new("AAPL".to_string());
+        book.add_order(Order {
+            order_id: "bid1".to_string(),
+            side: Side::Buy,
+            price: 100.00,
+            quantity: 10.0,
+            timestamp: current_timestamp_nanos(),
+            order_type: OrderType::Limit,
+        })
+        .unwrap();
+        book.add_order(Order {
+            order_id: "ask1".to_string(),
+            side: Side::Sell,
+            price: 100.10,
+            quantity: 20.0,
+            timestamp: current_timestamp_nanos(),
+            order_type: OrderType::Limit,
+        })
+        .unwrap();
+
+        let previous = book.get_level2_snapshot(10);
+
+        // Resize the bid, remove the ask, add a new bid.
+        book.apply_level_update(Side::Buy, 100.00, 15.0, 1);
+        book.apply_level_update(Side::Sell, 100.10, 0.0, 1);
+        book.apply_level_update(Side::Buy, 99.90, 5.0, 1);
+
+        let mut diffs = book.diff_against(&previous);
+        diffs.sort_by(|a, b| a.price.partial_cmp(&b.price).unwrap());
+
+        assert_eq!(diffs.len(), 3);
+        assert_eq!(diffs[0].price, 99.90);
+        assert_eq!(diffs[0].new_size, 5.0);
+        assert_eq!(diffs[1].price, 100.00);
+        assert_eq!(diffs[1].new_size, 15.0);
+        assert_eq!(diffs[2].price, 100.10);
+        assert_eq!(diffs[2].new_size, 0.0);
+    }
+
+    #[test]
+    fn test_cancel_order_removes_only_that_orders_quantity() {
+        let mut book = OrderBook::new("AAPL".to_string());
+
+        book.add_order(Order {
+            order_id: "buy1".to_string(),
+            side: Side::Buy,
+            price: 100.00,
+            quantity: 30.0,
+            timestamp: current_timestamp_nanos(),
+            order_type: OrderType::Limit,
+        })
+        .unwrap();
+        book.add_order(Order {
+            order_id: "buy2".to_string(),
+            side: Side::Buy,
+            price: 100.00,
+            quantity: 20.0,
+            timestamp: current_timestamp_nanos(),
+            order_type: OrderType::Limit,
+        })
+        .unwrap();
+
+        book.cancel_order("buy1").unwrap();
+
+        let bbo = book.get_best_bid_offer();
+        assert_eq!(bbo.bid_price, Some(100.00));
+        assert_eq!(bbo.bid_size, Some(20.0));
+        assert_eq!(book.get_statistics().total_bid_volume, 20.0);
+
+        // Cancelling the remaining order empties the level entirely.
+        book.cancel_order("buy2").unwrap();
+        assert_eq!(book.get_best_bid_offer().bid_price, None);
+    }
+
+    #[test]
+    fn test_match_order_decrements_consumed_quantity_out_of_resting_orders() {
+        let mut book = OrderBook::new("AAPL".to_string());
+
+        book.add_order(Order {
+            order_id: "b1".to_string(),
+            side: Side::Buy,
+            price: 100.00,
+            quantity: 10.0,
+            timestamp: 1,
+            order_type: OrderType::Limit,
+        })
+        .unwrap();
+        book.add_order(Order {
+            order_id: "b2".to_string(),
+            side: Side::Buy,
+            price: 100.00,
+            quantity: 5.0,
+            timestamp: 2,
+            order_type: OrderType::Limit,
+        })
+        .unwrap();
+
+        // An incoming sell for 10 @ 100 fully consumes b1 (price-time
+        // priority: b1 rested first) and leaves b2's 5 untouched.
+        book.match_order(Order {
+            order_id: "s1".to_string(),
+            side: Side::Sell,
+            price: 100.00,
+            quantity: 10.0,
+            timestamp: 3,
+            order_type: OrderType::Limit,
+        })
+        .unwrap();
+
+        let bbo = book.get_best_bid_offer();
+        assert_eq!(bbo.bid_size, Some(5.0));
+
+        // b1 was fully consumed by the match, so cancelling it must not
+        // touch the level (it must already be gone from `self.orders`).
+        assert!(book.cancel_order("b1").is_err());
+        assert_eq!(book.get_best_bid_offer().bid_size, Some(5.0));
+
+        // b2's resting 5 units are still cancellable and still correct.
+        book.cancel_order("b2").unwrap();
+        assert_eq!(book.get_best_bid_offer().bid_price, None);
+        assert_eq!(book.get_statistics().total_bid_volume, 0.0);
+    }
+
+    #[test]
+    fn test_cancel_order_rejects_unknown_order_id() {
+        let mut book = OrderBook::new("AAPL".to_string());
+        assert_eq!(
+            book.cancel_order("nonexistent"),
+            Err(OrderBookError::OrderNotFound { order_id: "nonexistent".to_string() })
+        );
+    }
+
+    #[test]
+    fn test_amend_order_adjusts_level_and_volume() {
+        let mut book = OrderBook::new("AAPL".to_string());
+
+        book.add_order(Order {
+            order_id: "sell1".to_string(),
+            side: Side::Sell,
+            price: 100.00,
+            quantity: 50.0,
+            timestamp: current_timestamp_nanos(),
+            order_type: OrderType::Limit,
+        })
+        .unwrap();
+
+        book.amend_order("sell1", 80.0).unwrap();
+        assert_eq!(book.get_best_bid_offer().ask_size, Some(80.0));
+        assert_eq!(book.get_statistics().total_ask_volume, 80.0);
+
+        // Amending down to zero behaves like a cancel.
+        book.amend_order("sell1", 0.0).unwrap();
+        assert_eq!(book.get_best_bid_offer().ask_price, None);
+    }
+
+    #[test]
+    fn test_reprice_pegs_follows_reference_price() {
+        let mut book = OrderBook::new("AAPL".to_string());
+
+        book.add_pegged_order(
+            PeggedOrder {
+                order_id: "peg_bid".to_string(),
+                side: Side::Buy,
+                peg_offset: -0.05,
+                limit_price: None,
+                quantity: 100.0,
+            },
+            100.00,
+        )
+        .unwrap();
+
+        assert_eq!(book.get_best_bid_offer().bid_price, Some(99.95));
+
+        book.reprice_pegs(101.00);
+        let bbo = book.get_best_bid_offer();
+        assert_eq!(bbo.bid_price, Some(100.95));
+        assert_eq!(bbo.bid_size, Some(100.0));
+        assert_eq!(book.get_statistics().total_bid_volume, 100.0);
+    }
+
+    #[test]
+    fn test_reprice_pegs_clamped_by_limit_price() {
+        let mut book = OrderBook::new("AAPL".to_string());
+
+        book.add_pegged_order(
+            PeggedOrder {
+                order_id: "peg_ask".to_string(),
+                side: Side::Sell,
+                peg_offset: 0.05,
+                limit_price: Some(100.20),
+                quantity: 50.0,
+            },
+            100.00,
+        )
+        .unwrap();
+        assert_eq!(book.get_best_bid_offer().ask_price, Some(100.05));
+
+        // Reference moves far enough that the unclamped peg price would
+        // undercut the limit; the peg must not price below it.
+        book.reprice_pegs(100.30);
+        assert_eq!(book.get_best_bid_offer().ask_price, Some(100.20));
+    }
+
+    #[test]
+    fn test_reprice_pegs_noop_when_price_unchanged() {
+        let mut book = OrderBook::new("AAPL".to_string());
+
+        book.add_pegged_order(
+            PeggedOrder {
+                order_id: "peg_bid".to_string(),
+                side: Side::Buy,
+                peg_offset: -0.05,
+                limit_price: None,
+                quantity: 100.0,
+            },
+            100.00,
+        )
+        .unwrap();
+
+        let sequence_before = book.sequence();
+        book.reprice_pegs(100.00);
+        assert_eq!(book.sequence(), sequence_before);
+    }
+
+    #[test]
+    fn test_match_order_fully_consumed_peg_does_not_reappear_after_reprice() {
+        let mut book = OrderBook::new("AAPL".to_string());
+
+        book.add_pegged_order(
+            PeggedOrder {
+                order_id: "peg_bid".to_string(),
+                side: Side::Buy,
+                peg_offset: -1.00,
+                limit_price: None,
+                quantity: 100.0,
+            },
+            100.00,
+        )
+        .unwrap();
+        assert_eq!(book.get_best_bid_offer().bid_price, Some(99.00));
+
+        // An incoming IOC sell fully consumes the peg's resting liquidity.
+        book.match_order(Order {
+            order_id: "s1".to_string(),
+            side: Side::Sell,
+            price: 99.00,
+            quantity: 100.0,
+            timestamp: current_timestamp_nanos(),
+            order_type: OrderType::ImmediateOrCancel,
+        })
+        .unwrap();
+        assert_eq!(book.get_best_bid_offer().bid_price, None);
+        assert_eq!(book.get_statistics().total_bid_volume, 0.0);
+
+        // The reference price moves; a stale, un-decremented peg would
+        // reappear here with its original quantity.
+        book.reprice_pegs(101.00);
+        assert_eq!(book.get_best_bid_offer().bid_price, None);
+        assert_eq!(book.get_statistics().total_bid_volume, 0.0);
+    }
+
+    #[test]
+    fn test_simulate_fill_walks_multiple_levels() {
+        let mut book = OrderBook::new("AAPL".to_string());
+
+        book.add_order(Order {
+            order_id: "bid1".to_string(),
+            side: Side::Buy,
+            price: 99.90,
+            quantity: 10.0,
+            timestamp: current_timestamp_nanos(),
+            order_type: OrderType::Limit,
+        })
+        .unwrap();
+        book.add_order(Order {
+            order_id: "ask1".to_string(),
+            side: Side::Sell,
+            price: 100.00,
+            quantity: 10.0,
+            timestamp: current_timestamp_nanos(),
+            order_type: OrderType::Limit,
+        })
+        .unwrap();
+        book.add_order(Order {
+            order_id: "ask2".to_string(),
+            side: Side::Sell,
+            price: 100.10,
+            quantity: 20.0,
+            timestamp: current_timestamp_nanos(),
+            order_type: OrderType::Limit,
+        })
+        .unwrap();
+
+        let estimate = book.simulate_fill(Side::Buy, 15.0);
+
+        assert_eq!(estimate.filled_quantity, 15.0);
+        assert_eq!(estimate.levels_consumed, 2);
+        assert_eq!(estimate.worst_price, 100.10);
+        assert!(!estimate.partial);
+
+        // avg_price = (10*100.00 + 5*100.10) / 15
+        let expected_avg = (10.0 * 100.00 + 5.0 * 100.10) / 15.0;
+        assert!((estimate.avg_price - expected_avg).abs() < 1e-9);
+
+        let mid = book.get_best_bid_offer().mid_price.unwrap();
+        let expected_slippage = (expected_avg - mid) / mid * 10000.0;
+        assert!((estimate.slippage_bps - expected_slippage).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_simulate_fill_flags_partial_when_depth_insufficient() {
+        let mut book = OrderBook::new("AAPL".to_string());
+
+        book.add_order(Order {
+            order_id: "ask1".to_string(),
+            side: Side::Sell,
+            price: 100.00,
+            quantity: 10.0,
+            timestamp: current_timestamp_nanos(),
+            order_type: OrderType::Limit,
+        })
+        .unwrap();
+
+        let estimate = book.simulate_fill(Side::Buy, 50.0);
+
+        assert_eq!(estimate.filled_quantity, 10.0);
+        assert_eq!(estimate.levels_consumed, 1);
+        assert!(estimate.partial);
+    }
+
+    #[test]
+    fn test_simulate_fill_on_empty_book_side() {
+        let book = OrderBook::new("AAPL".to_string());
+        let estimate = book.simulate_fill(Side::Sell, 10.0);
+
+        assert_eq!(estimate.filled_quantity, 0.0);
+        assert_eq!(estimate.levels_consumed, 0);
+        assert_eq!(estimate.avg_price, 0.0);
+        assert_eq!(estimate.slippage_bps, 0.0);
+        assert!(estimate.partial);
+    }
 }
\ No newline at end of file
@@ -1,38 +1,120 @@
-use rocksdb::{DB, Options, WriteBatch, IteratorMode};
+use rocksdb::{DB, Options, WriteBatch, IteratorMode, ColumnFamily, ColumnFamilyDescriptor};
 use serde::{Serialize, Deserialize};
 use std::path::Path;
 use std::sync::Arc;
+use std::collections::{HashMap, HashSet};
+use std::time::{Duration, Instant};
+use tokio::sync::mpsc;
+use tracing::{debug, error, info};
 use anyhow::Result;
 use rmp_serde as msgpack;
+use crate::fixed_price::FixedPrice;
+
+/// Monetary values stored in `PortfolioState`/`Position`/`TimeSeriesPoint`
+/// are `f64` by default, switching to the checked 128-bit `FixedPrice` type
+/// under the `fixed-price` feature so persisted balances round-trip exactly
+/// instead of accumulating `f64` summation error across many writes.
+#[cfg(not(feature = "fixed-price"))]
+pub type Price = f64;
+#[cfg(feature = "fixed-price")]
+pub type Price = FixedPrice;
+
+/// Convert a computed `f64` (e.g. a `FinancialCalculator` result) into the
+/// stored `Price` representation at the persistence boundary.
+#[cfg(not(feature = "fixed-price"))]
+fn price_from_f64(value: f64) -> Price {
+    value
+}
+#[cfg(feature = "fixed-price")]
+fn price_from_f64(value: f64) -> Price {
+    FixedPrice::from_f64(value).expect("monetary value must be finite and in range")
+}
+
+/// Convert a stored `Price` back to `f64` so `FinancialCalculator` can keep
+/// doing floating-point math internally.
+#[cfg(not(feature = "fixed-price"))]
+fn price_to_f64(value: Price) -> f64 {
+    value
+}
+#[cfg(feature = "fixed-price")]
+fn price_to_f64(value: Price) -> f64 {
+    value.to_f64()
+}
+
+/// Column family names used by `UltraFastDB`.
+pub const CF_DEFAULT: &str = "default";
+pub const CF_PORTFOLIO: &str = "portfolio";
+pub const CF_METRICS: &str = "metrics";
+pub const CF_RESULTS: &str = "results";
 
 /// Ultra-fast RocksDB-based persistence for calculation results
 pub struct UltraFastDB {
     db: Arc<DB>,
+    excludes_from_compaction: HashSet<String>,
 }
 
 impl UltraFastDB {
-    /// Creates a new optimized RocksDB instance
+    /// Creates a new optimized RocksDB instance, opened with named column
+    /// families instead of a single default CF: `portfolio` (tuned for point
+    /// lookups), `metrics` (tuned for heavy sequential writes and excluded
+    /// from compaction since it's hot), and `results`.
     pub fn new<P: AsRef<Path>>(path: P) -> Result<Self> {
-        let mut opts = Options::default();
-
-        // Optimize for write-heavy workloads
-        opts.create_if_missing(true);
-        opts.set_max_background_jobs(8);
-        opts.set_max_write_buffer_number(6);
-        opts.set_write_buffer_size(128 * 1024 * 1024); // 128MB
-        opts.set_target_file_size_base(256 * 1024 * 1024); // 256MB
-        opts.set_compression_type(rocksdb::DBCompressionType::Lz4);
+        let mut db_opts = Options::default();
+        db_opts.create_if_missing(true);
+        db_opts.create_missing_column_families(true);
+        db_opts.set_max_background_jobs(8);
 
         // Enable WAL for durability with minimal performance impact
-        opts.set_wal_ttl_seconds(300); // 5 minutes
-        opts.set_wal_size_limit_mb(1024); // 1GB
+        db_opts.set_wal_ttl_seconds(300); // 5 minutes
+        db_opts.set_wal_size_limit_mb(1024); // 1GB
 
         // Optimize for SSD
-        opts.set_allow_mmap_reads(true);
-        opts.set_allow_mmap_writes(false); // Safer for writes
+        db_opts.set_allow_mmap_reads(true);
+        db_opts.set_allow_mmap_writes(false); // Safer for writes
+
+        let mut default_opts = Options::default();
+        default_opts.set_compression_type(rocksdb::DBCompressionType::Lz4);
+
+        // portfolio: point lookups by portfolio_id/timestamp.
+        let mut portfolio_opts = Options::default();
+        portfolio_opts.set_compression_type(rocksdb::DBCompressionType::Lz4);
+        portfolio_opts.optimize_for_point_lookup(64); // 64MB block cache
+
+        // metrics: heavy sequential writes; disable auto compaction since
+        // this CF is hot and frequently written, so compaction shouldn't
+        // compete with the write path.
+        let mut metrics_opts = Options::default();
+        metrics_opts.set_compression_type(rocksdb::DBCompressionType::Lz4);
+        metrics_opts.set_max_write_buffer_number(6);
+        metrics_opts.set_write_buffer_size(128 * 1024 * 1024); // 128MB
+        metrics_opts.set_target_file_size_base(256 * 1024 * 1024); // 256MB
+        metrics_opts.set_disable_auto_compactions(true);
+
+        let mut results_opts = Options::default();
+        results_opts.set_compression_type(rocksdb::DBCompressionType::Lz4);
+
+        let cf_descriptors = vec![
+            ColumnFamilyDescriptor::new(CF_DEFAULT, default_opts),
+            ColumnFamilyDescriptor::new(CF_PORTFOLIO, portfolio_opts),
+            ColumnFamilyDescriptor::new(CF_METRICS, metrics_opts),
+            ColumnFamilyDescriptor::new(CF_RESULTS, results_opts),
+        ];
+
+        let db = DB::open_cf_descriptors(&db_opts, path, cf_descriptors)?;
+        let excludes_from_compaction = [CF_METRICS.to_string()].into_iter().collect();
+
+        Ok(Self { db: Arc::new(db), excludes_from_compaction })
+    }
 
-        let db = DB::open(&opts, path)?;
-        Ok(Self { db: Arc::new(db) })
+    /// Column families excluded from compaction (hot, frequently-written CFs).
+    pub fn excludes_from_compaction(&self) -> &HashSet<String> {
+        &self.excludes_from_compaction
+    }
+
+    fn cf_handle(&self, cf: &str) -> Result<&ColumnFamily> {
+        self.db
+            .cf_handle(cf)
+            .ok_or_else(|| anyhow::anyhow!("Unknown column family: {}", cf))
     }
 
     /// Store data with MessagePack serialization (2x faster than JSON)
@@ -41,9 +123,7 @@ impl UltraFastDB {
         K: AsRef<[u8]>,
         V: Serialize,
     {
-        let serialized = msgpack::to_vec(value)?;
-        self.db.put(key, serialized)?;
-        Ok(())
+        self.put_cf(CF_DEFAULT, key, value)
     }
 
     /// Retrieve and deserialize data
@@ -52,7 +132,54 @@ impl UltraFastDB {
         K: AsRef<[u8]>,
         V: for<'de> Deserialize<'de>,
     {
-        match self.db.get(key)? {
+        self.get_cf(CF_DEFAULT, key)
+    }
+
+    /// Batch write for maximum throughput
+    pub fn batch_write<K, V>(&self, entries: Vec<(K, V)>) -> Result<()>
+    where
+        K: AsRef<[u8]>,
+        V: Serialize,
+    {
+        self.batch_write_cf(CF_DEFAULT, entries)
+    }
+
+    /// Delete a key
+    pub fn delete<K>(&self, key: K) -> Result<()>
+    where
+        K: AsRef<[u8]>,
+    {
+        self.delete_cf(CF_DEFAULT, key)
+    }
+
+    /// Scan with prefix for range queries
+    pub fn scan_prefix<V>(&self, prefix: &[u8]) -> Result<Vec<(Vec<u8>, V)>>
+    where
+        V: for<'de> Deserialize<'de>,
+    {
+        self.scan_prefix_cf(CF_DEFAULT, prefix)
+    }
+
+    /// Store data with MessagePack serialization in a specific column family.
+    pub fn put_cf<K, V>(&self, cf: &str, key: K, value: &V) -> Result<()>
+    where
+        K: AsRef<[u8]>,
+        V: Serialize,
+    {
+        let handle = self.cf_handle(cf)?;
+        let serialized = msgpack::to_vec(value)?;
+        self.db.put_cf(handle, key, serialized)?;
+        Ok(())
+    }
+
+    /// Retrieve and deserialize data from a specific column family.
+    pub fn get_cf<K, V>(&self, cf: &str, key: K) -> Result<Option<V>>
+    where
+        K: AsRef<[u8]>,
+        V: for<'de> Deserialize<'de>,
+    {
+        let handle = self.cf_handle(cf)?;
+        match self.db.get_cf(handle, key)? {
             Some(data) => {
                 let value: V = msgpack::from_slice(&data)?;
                 Ok(Some(value))
@@ -61,39 +188,42 @@ impl UltraFastDB {
         }
     }
 
-    /// Batch write for maximum throughput
-    pub fn batch_write<K, V>(&self, entries: Vec<(K, V)>) -> Result<()>
+    /// Batch write for maximum throughput within a specific column family.
+    pub fn batch_write_cf<K, V>(&self, cf: &str, entries: Vec<(K, V)>) -> Result<()>
     where
         K: AsRef<[u8]>,
         V: Serialize,
     {
+        let handle = self.cf_handle(cf)?;
         let mut batch = WriteBatch::default();
 
         for (key, value) in entries {
             let serialized = msgpack::to_vec(&value)?;
-            batch.put(key, serialized);
+            batch.put_cf(handle, key, serialized);
         }
 
         self.db.write(batch)?;
         Ok(())
     }
 
-    /// Delete a key
-    pub fn delete<K>(&self, key: K) -> Result<()>
+    /// Delete a key from a specific column family.
+    pub fn delete_cf<K>(&self, cf: &str, key: K) -> Result<()>
     where
         K: AsRef<[u8]>,
     {
-        self.db.delete(key)?;
+        let handle = self.cf_handle(cf)?;
+        self.db.delete_cf(handle, key)?;
         Ok(())
     }
 
-    /// Scan with prefix for range queries
-    pub fn scan_prefix<V>(&self, prefix: &[u8]) -> Result<Vec<(Vec<u8>, V)>>
+    /// Scan with prefix for range queries within a specific column family.
+    pub fn scan_prefix_cf<V>(&self, cf: &str, prefix: &[u8]) -> Result<Vec<(Vec<u8>, V)>>
     where
         V: for<'de> Deserialize<'de>,
     {
+        let handle = self.cf_handle(cf)?;
         let mut results = Vec::new();
-        let iter = self.db.iterator(IteratorMode::From(prefix, rocksdb::Direction::Forward));
+        let iter = self.db.iterator_cf(handle, IteratorMode::From(prefix, rocksdb::Direction::Forward));
 
         for item in iter {
             let (key, value) = item?;
@@ -110,6 +240,31 @@ impl UltraFastDB {
         Ok(results)
     }
 
+    /// Seek directly to the newest entry matching `prefix`, in a single
+    /// reverse iterator step from `seek_key` (typically `prefix` with the
+    /// key's variable suffix replaced by its maximum value), instead of
+    /// scanning and deserializing the whole prefix range. `seek_key` must
+    /// sort at or after every real key under `prefix`.
+    pub fn get_last_with_prefix<V>(&self, cf: &str, seek_key: &[u8], prefix: &[u8]) -> Result<Option<V>>
+    where
+        V: for<'de> Deserialize<'de>,
+    {
+        let handle = self.cf_handle(cf)?;
+        let mut iter = self.db.iterator_cf(handle, IteratorMode::From(seek_key, rocksdb::Direction::Reverse));
+
+        match iter.next() {
+            Some(item) => {
+                let (key, value) = item?;
+                if !key.starts_with(prefix) {
+                    return Ok(None);
+                }
+                let deserialized: V = msgpack::from_slice(&value)?;
+                Ok(Some(deserialized))
+            }
+            None => Ok(None),
+        }
+    }
+
     /// Get database statistics
     pub fn stats(&self) -> Result<String> {
         Ok(self.db.property_value("rocksdb.stats")?.unwrap_or_default())
@@ -123,54 +278,99 @@ impl UltraFastDB {
 }
 
 /// Time-series data structure for financial metrics
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TimeSeriesPoint {
     pub timestamp: u64,
-    pub value: f64,
+    pub value: Price,
     pub metadata: Option<Vec<u8>>,
 }
 
 /// Portfolio state optimized for fast serialization
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct PortfolioState {
     pub portfolio_id: String,
     pub timestamp: u64,
-    pub total_value: f64,
+    pub total_value: Price,
     pub positions: Vec<Position>,
-    pub cash: f64,
-    pub unrealized_pnl: f64,
-    pub realized_pnl: f64,
+    pub cash: Price,
+    pub unrealized_pnl: Price,
+    pub realized_pnl: Price,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Position {
     pub symbol: String,
     pub quantity: f64,
-    pub avg_cost: f64,
-    pub current_price: f64,
-    pub unrealized_pnl: f64,
+    pub avg_cost: Price,
+    pub current_price: Price,
+    pub unrealized_pnl: Price,
+}
+
+impl Position {
+    /// Build a position from raw `f64` market values, converting into the
+    /// stored `Price` representation at the persistence boundary.
+    pub fn new(symbol: impl Into<String>, quantity: f64, avg_cost: f64, current_price: f64) -> Self {
+        let unrealized_pnl = (current_price - avg_cost) * quantity;
+        Self {
+            symbol: symbol.into(),
+            quantity,
+            avg_cost: price_from_f64(avg_cost),
+            current_price: price_from_f64(current_price),
+            unrealized_pnl: price_from_f64(unrealized_pnl),
+        }
+    }
+
+    /// Re-mark the position to a new price, recomputing the stored
+    /// unrealized P&L as `(current_price - avg_cost) * quantity`: the
+    /// inputs/outputs at this pricing boundary are `f64`, the fields stored
+    /// for persistence are `Price`.
+    pub fn mark_to_market(&mut self, current_price: f64) {
+        let avg_cost = price_to_f64(self.avg_cost);
+        self.current_price = price_from_f64(current_price);
+        self.unrealized_pnl = price_from_f64((current_price - avg_cost) * self.quantity);
+    }
+}
+
+// Timestamps are zero-padded to this width (u64::MAX has 20 decimal digits)
+// so that keys sort lexicographically in timestamp order, making reverse
+// iteration from the prefix's maximum key land on the newest entry.
+const TIMESTAMP_WIDTH: usize = 20;
+
+fn portfolio_key(portfolio_id: &str, timestamp: u64) -> String {
+    format!("portfolio:{}:{:0width$}", portfolio_id, timestamp, width = TIMESTAMP_WIDTH)
+}
+
+fn metric_key(metric_name: &str, timestamp: u64) -> String {
+    format!("metric:{}:{:0width$}", metric_name, timestamp, width = TIMESTAMP_WIDTH)
 }
 
 impl UltraFastDB {
-    /// Store portfolio state with timestamp-based key
+    /// Store portfolio state with timestamp-based key in the `portfolio` CF.
     pub fn store_portfolio_state(&self, state: &PortfolioState) -> Result<()> {
-        let key = format!("portfolio:{}:{}", state.portfolio_id, state.timestamp);
-        self.put(key.as_bytes(), state)
+        let key = portfolio_key(&state.portfolio_id, state.timestamp);
+        self.put_cf(CF_PORTFOLIO, key.as_bytes(), state)
     }
 
-    /// Retrieve latest portfolio state
+    /// Retrieve latest portfolio state with a single reverse seek instead of
+    /// scanning and deserializing the full history.
     pub fn get_latest_portfolio_state(&self, portfolio_id: &str) -> Result<Option<PortfolioState>> {
         let prefix = format!("portfolio:{}:", portfolio_id);
-        let states = self.scan_prefix::<PortfolioState>(prefix.as_bytes())?;
-
-        // Get the most recent state (keys are timestamp-ordered)
-        Ok(states.into_iter().last().map(|(_, state)| state))
+        let seek_key = portfolio_key(portfolio_id, u64::MAX);
+        self.get_last_with_prefix(CF_PORTFOLIO, seek_key.as_bytes(), prefix.as_bytes())
     }
 
-    /// Store time-series data point
+    /// Store time-series data point in the `metrics` CF.
     pub fn store_metric(&self, metric_name: &str, point: &TimeSeriesPoint) -> Result<()> {
-        let key = format!("metric:{}:{}", metric_name, point.timestamp);
-        self.put(key.as_bytes(), point)
+        let key = metric_key(metric_name, point.timestamp);
+        self.put_cf(CF_METRICS, key.as_bytes(), point)
+    }
+
+    /// Retrieve only the most recent point for a metric with a single
+    /// reverse seek, instead of scanning and deserializing its full range.
+    pub fn get_latest_metric(&self, metric_name: &str) -> Result<Option<TimeSeriesPoint>> {
+        let prefix = format!("metric:{}:", metric_name);
+        let seek_key = metric_key(metric_name, u64::MAX);
+        self.get_last_with_prefix(CF_METRICS, seek_key.as_bytes(), prefix.as_bytes())
     }
 
     /// Get time-series data for a metric within a time range
@@ -181,7 +381,7 @@ impl UltraFastDB {
         end_time: u64,
     ) -> Result<Vec<TimeSeriesPoint>> {
         let prefix = format!("metric:{}:", metric_name);
-        let all_points = self.scan_prefix::<TimeSeriesPoint>(prefix.as_bytes())?;
+        let all_points = self.scan_prefix_cf::<TimeSeriesPoint>(CF_METRICS, prefix.as_bytes())?;
 
         let filtered: Vec<TimeSeriesPoint> = all_points
             .into_iter()
@@ -193,6 +393,174 @@ impl UltraFastDB {
     }
 }
 
+/// A write submitted to the background `PersistenceService`.
+enum PersistenceMessage {
+    Portfolio(PortfolioState),
+    Metric(String, TimeSeriesPoint),
+}
+
+/// Handle calculation threads use to submit writes without blocking on disk
+/// I/O; the actual RocksDB batches are issued by the `PersistenceService`
+/// task this handle is paired with.
+#[derive(Clone)]
+pub struct PersistenceHandle {
+    sender: mpsc::Sender<PersistenceMessage>,
+}
+
+impl PersistenceHandle {
+    /// Queue a portfolio state write. Backpressures (awaits) if the
+    /// service's channel is full rather than blocking on RocksDB itself.
+    pub async fn store_portfolio_state(&self, state: PortfolioState) -> Result<()> {
+        self.sender
+            .send(PersistenceMessage::Portfolio(state))
+            .await
+            .map_err(|_| anyhow::anyhow!("persistence service channel closed"))
+    }
+
+    /// Queue a metric point write.
+    pub async fn store_metric(&self, metric_name: impl Into<String>, point: TimeSeriesPoint) -> Result<()> {
+        self.sender
+            .send(PersistenceMessage::Metric(metric_name.into(), point))
+            .await
+            .map_err(|_| anyhow::anyhow!("persistence service channel closed"))
+    }
+}
+
+/// Background service that owns the `UltraFastDB` and decouples calculation
+/// threads from disk I/O: writes arrive over a bounded channel, get
+/// coalesced into per-column-family `WriteBatch`es, and are flushed once
+/// `batch_size` staged writes have accumulated or `flush_interval` has elapsed,
+/// whichever comes first. A write is only staged if it actually changes the
+/// last known state for its id, so repeated no-op submissions never reach
+/// RocksDB.
+pub struct PersistenceService {
+    db: UltraFastDB,
+    receiver: mpsc::Receiver<PersistenceMessage>,
+    batch_size: usize,
+    flush_interval: Duration,
+    last_portfolio_state: HashMap<String, PortfolioState>,
+    last_metric_point: HashMap<String, TimeSeriesPoint>,
+}
+
+impl PersistenceService {
+    /// Creates the service and the handle calculation threads submit writes
+    /// through. `channel_capacity` bounds the pending-write queue;
+    /// `batch_size` and `flush_interval` control how eagerly it flushes.
+    pub fn new(
+        db: UltraFastDB,
+        channel_capacity: usize,
+        batch_size: usize,
+        flush_interval: Duration,
+    ) -> (Self, PersistenceHandle) {
+        let (sender, receiver) = mpsc::channel(channel_capacity);
+        let service = Self {
+            db,
+            receiver,
+            batch_size,
+            flush_interval,
+            last_portfolio_state: HashMap::new(),
+            last_metric_point: HashMap::new(),
+        };
+        (service, PersistenceHandle { sender })
+    }
+
+    /// Restore the latest persisted state of every known portfolio, keyed by
+    /// portfolio id, for repopulating an in-memory engine on startup.
+    pub fn restore_portfolio_states(&self) -> Result<HashMap<String, PortfolioState>> {
+        let all = self.db.scan_prefix_cf::<PortfolioState>(CF_PORTFOLIO, b"portfolio:")?;
+        let mut latest: HashMap<String, PortfolioState> = HashMap::new();
+
+        for (_, state) in all {
+            latest
+                .entry(state.portfolio_id.clone())
+                .and_modify(|existing| {
+                    if state.timestamp > existing.timestamp {
+                        *existing = state.clone();
+                    }
+                })
+                .or_insert(state);
+        }
+
+        Ok(latest)
+    }
+
+    /// Run the coalesce-and-flush loop until every `PersistenceHandle` is
+    /// dropped and the channel drains. Intended to be spawned with
+    /// `tokio::spawn(service.run())`.
+    pub async fn run(mut self) {
+        let mut portfolio_batch: Vec<(String, PortfolioState)> = Vec::new();
+        let mut metric_batch: Vec<(String, TimeSeriesPoint)> = Vec::new();
+        let mut last_flush = Instant::now();
+
+        loop {
+            let time_left = self.flush_interval.saturating_sub(last_flush.elapsed());
+
+            tokio::select! {
+                maybe_msg = self.receiver.recv() => {
+                    match maybe_msg {
+                        Some(PersistenceMessage::Portfolio(state)) => {
+                            if self.last_portfolio_state.get(&state.portfolio_id) != Some(&state) {
+                                self.last_portfolio_state.insert(state.portfolio_id.clone(), state.clone());
+                                let key = portfolio_key(&state.portfolio_id, state.timestamp);
+                                portfolio_batch.push((key, state));
+                            }
+                        }
+                        Some(PersistenceMessage::Metric(name, point)) => {
+                            if self.last_metric_point.get(&name) != Some(&point) {
+                                self.last_metric_point.insert(name.clone(), point.clone());
+                                let key = metric_key(&name, point.timestamp);
+                                metric_batch.push((key, point));
+                            }
+                        }
+                        None => {
+                            self.flush(&mut portfolio_batch, &mut metric_batch);
+                            break;
+                        }
+                    }
+
+                    if portfolio_batch.len() + metric_batch.len() >= self.batch_size {
+                        self.flush(&mut portfolio_batch, &mut metric_batch);
+                        last_flush = Instant::now();
+                    }
+                }
+                _ = tokio::time::sleep(time_left) => {
+                    self.flush(&mut portfolio_batch, &mut metric_batch);
+                    last_flush = Instant::now();
+                }
+            }
+        }
+    }
+
+    /// Issue one `WriteBatch` per non-empty column family and report timing
+    /// via `tracing`. A no-op if nothing has changed since the last flush.
+    fn flush(&self, portfolio_batch: &mut Vec<(String, PortfolioState)>, metric_batch: &mut Vec<(String, TimeSeriesPoint)>) {
+        if portfolio_batch.is_empty() && metric_batch.is_empty() {
+            debug!("persistence flush skipped: no pending writes");
+            return;
+        }
+
+        let flushed = portfolio_batch.len() + metric_batch.len();
+        let start = Instant::now();
+
+        if !portfolio_batch.is_empty() {
+            if let Err(err) = self.db.batch_write_cf(CF_PORTFOLIO, std::mem::take(portfolio_batch)) {
+                error!("portfolio batch flush failed: {}", err);
+            }
+        }
+        if !metric_batch.is_empty() {
+            if let Err(err) = self.db.batch_write_cf(CF_METRICS, std::mem::take(metric_batch)) {
+                error!("metric batch flush failed: {}", err);
+            }
+        }
+
+        info!(
+            batch_size = flushed,
+            flush_latency_us = start.elapsed().as_micros() as u64,
+            "persistence batch flushed"
+        );
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -271,4 +639,61 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_get_latest_picks_newest_by_value_not_insertion_order() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let db = UltraFastDB::new(temp_dir.path())?;
+
+        for timestamp in [1_640_995_200_u64, 1_700_000_000, 1_650_000_000] {
+            db.store_metric(
+                "latency_ms",
+                &TimeSeriesPoint { timestamp, value: timestamp as f64, metadata: None },
+            )?;
+        }
+
+        let latest = db.get_latest_metric("latency_ms")?.expect("metric should exist");
+        assert_eq!(latest.timestamp, 1_700_000_000);
+
+        assert!(db.get_latest_metric("no_such_metric")?.is_none());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_persistence_service_coalesces_and_dedupes() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let db = UltraFastDB::new(temp_dir.path())?;
+        let (service, handle) = PersistenceService::new(db, 16, 2, Duration::from_secs(60));
+        let run_handle = tokio::spawn(service.run());
+
+        let state = PortfolioState {
+            portfolio_id: "svc_portfolio".to_string(),
+            timestamp: 1,
+            total_value: 1000.0,
+            positions: vec![],
+            cash: 1000.0,
+            unrealized_pnl: 0.0,
+            realized_pnl: 0.0,
+        };
+
+        // Submitting the identical state twice should only flush one write.
+        handle.store_portfolio_state(state.clone()).await?;
+        handle.store_portfolio_state(state.clone()).await?;
+        handle
+            .store_metric("latency_ms", TimeSeriesPoint { timestamp: 1, value: 5.0, metadata: None })
+            .await?;
+
+        drop(handle);
+        run_handle.await?;
+
+        let db = UltraFastDB::new(temp_dir.path())?;
+        let restored = db.get_latest_portfolio_state("svc_portfolio")?;
+        assert_eq!(restored.unwrap().total_value, 1000.0);
+
+        let restored_all = PersistenceService::new(db, 1, 1, Duration::from_secs(1)).0.restore_portfolio_states()?;
+        assert!(restored_all.contains_key("svc_portfolio"));
+
+        Ok(())
+    }
 }
\ No newline at end of file
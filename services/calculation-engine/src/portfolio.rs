@@ -1,12 +1,209 @@
 use anyhow::Result;
-use nalgebra::{DMatrix, DVector};
+use nalgebra::{Cholesky, DMatrix, DVector};
+use rand::distributions::Distribution;
+use rayon::prelude::*;
+use statrs::distribution::{ChiSquared, ContinuousCDF, Normal};
 use std::collections::HashMap;
 use chrono::{DateTime, Utc};
+use crate::financial::FinancialCalculator;
+use crate::cache_optimized::CacheOptimizedMarketData;
+use crate::risk::RiskCalculator;
 
 pub struct PortfolioAnalyzer {
     // Configuration and state for portfolio analysis
 }
 
+/// Result of `minimize_cvar`: the optimal portfolio weights alongside the
+/// Rockafellar-Uryasev auxiliary variable, which at optimality equals the
+/// portfolio's Value at Risk at the same confidence level.
+#[derive(Debug, Clone)]
+pub struct CvarOptimizationResult {
+    pub weights: Vec<f64>,
+    pub var: f64,
+    pub cvar: f64,
+}
+
+/// A single investor view for `black_litterman_optimization`: a linear
+/// combination of asset weights (one row of the pick matrix P), the view's
+/// expected value (one entry of Q), and a confidence in (0, 1] used to
+/// derive the view's uncertainty when no explicit Ω matrix is supplied.
+#[derive(Debug, Clone)]
+pub struct BlackLittermanView {
+    pub asset_weights: Vec<f64>,
+    pub expected_value: f64,
+    pub confidence: f64,
+}
+
+/// Sample mean
+fn mean(data: &[f64]) -> f64 {
+    data.iter().sum::<f64>() / data.len() as f64
+}
+
+/// Sample standard deviation (n-1 denominator)
+fn std_dev(data: &[f64]) -> f64 {
+    let m = mean(data);
+    let n = data.len() as f64;
+    (data.iter().map(|x| (x - m).powi(2)).sum::<f64>() / (n - 1.0)).sqrt()
+}
+
+/// Sample skewness (Fisher-Pearson, not bias-corrected)
+fn skewness(data: &[f64]) -> f64 {
+    let m = mean(data);
+    let s = std_dev(data);
+    let n = data.len() as f64;
+    if s == 0.0 {
+        return 0.0;
+    }
+    data.iter().map(|x| ((x - m) / s).powi(3)).sum::<f64>() / n
+}
+
+/// Sample excess kurtosis (kurtosis - 3), not bias-corrected
+fn excess_kurtosis(data: &[f64]) -> f64 {
+    let m = mean(data);
+    let s = std_dev(data);
+    let n = data.len() as f64;
+    if s == 0.0 {
+        return 0.0;
+    }
+    data.iter().map(|x| ((x - m) / s).powi(4)).sum::<f64>() / n - 3.0
+}
+
+/// Result of `risk_budgeting_portfolio`: the normalized weights alongside
+/// each asset's realized fractional contribution to portfolio variance, so
+/// callers can verify convergence against the requested risk budgets.
+#[derive(Debug, Clone)]
+pub struct RiskBudgetingResult {
+    pub weights: Vec<f64>,
+    pub risk_contributions: Vec<f64>,
+}
+
+/// Result of `parametric_portfolio_policy`: the fitted characteristic
+/// coefficients θ alongside the resulting per-period weight time series.
+#[derive(Debug, Clone)]
+pub struct ParametricPolicyResult {
+    pub theta: Vec<f64>,
+    pub weights: Vec<Vec<f64>>,
+}
+
+/// Return-generating distribution for `monte_carlo_simulation`.
+#[derive(Debug, Clone, Copy)]
+pub enum ReturnDistribution {
+    Gaussian,
+    StudentsT { degrees_of_freedom: f64 },
+}
+
+/// Result of `monte_carlo_simulation`: the simulated terminal outcome
+/// distribution plus summary risk statistics at the requested confidence
+/// level.
+#[derive(Debug, Clone)]
+pub struct MonteCarloResult {
+    pub outcomes: Vec<f64>,
+    pub mean_terminal_value: f64,
+    pub simulated_var: f64,
+    pub simulated_cvar: f64,
+}
+
+/// Full performance tear-sheet produced by `performance_summary`.
+#[derive(Debug, Clone, Copy)]
+pub struct PerformanceSummary {
+    pub annualized_return: f64,
+    pub annualized_volatility: f64,
+    pub max_drawdown: f64,
+    pub calmar_ratio: f64,
+    pub pain_index: f64,
+    pub pain_ratio: f64,
+    pub information_ratio: Option<f64>,
+    pub kelly_fraction: f64,
+    pub adjusted_sharpe: f64,
+    pub upside_potential_ratio: f64,
+    pub average_drawdown_duration: f64,
+    pub longest_drawdown_duration: u32,
+}
+
+/// Cornish-Fisher expansion of the standard-normal quantile `z`, adjusted for
+/// sample skewness `s` and excess kurtosis `k`.
+fn cornish_fisher_quantile(z: f64, s: f64, k: f64) -> f64 {
+    z + (z.powi(2) - 1.0) / 6.0 * s + (z.powi(3) - 3.0 * z) / 24.0 * k - (2.0 * z.powi(3) - 5.0 * z) / 36.0 * s.powi(2)
+}
+
+/// Drawdown at each point of a value series: the fractional decline from the
+/// running peak, 0.0 while at a new high.
+fn drawdown_series(values: &[f64]) -> Vec<f64> {
+    let mut peak = values[0];
+    values
+        .iter()
+        .map(|&v| {
+            if v > peak {
+                peak = v;
+            }
+            if peak > 0.0 {
+                (peak - v) / peak
+            } else {
+                0.0
+            }
+        })
+        .collect()
+}
+
+/// Linear-interpolated percentile of a slice (0.0 <= q <= 1.0). Sorts a copy.
+fn percentile(data: &[f64], q: f64) -> f64 {
+    let mut sorted = data.to_vec();
+    sorted.sort_by(|a, b| a.total_cmp(b));
+    let n = sorted.len();
+    if n == 1 {
+        return sorted[0];
+    }
+    let pos = q * (n - 1) as f64;
+    let lower = pos.floor() as usize;
+    let upper = pos.ceil() as usize;
+    if lower == upper {
+        sorted[lower]
+    } else {
+        let frac = pos - lower as f64;
+        sorted[lower] * (1.0 - frac) + sorted[upper] * frac
+    }
+}
+
+/// Matrix square root L of a covariance matrix such that Σ ≈ L·Lᵀ. Prefers
+/// the Cholesky factorization; if Σ is only positive-semidefinite (Cholesky
+/// fails), falls back to an eigen-decomposition square root with negative
+/// eigenvalues clipped to zero.
+fn covariance_sqrt(covariance: &DMatrix<f64>) -> Result<DMatrix<f64>> {
+    if let Some(chol) = Cholesky::new(covariance.clone()) {
+        return Ok(chol.l());
+    }
+
+    let eig = covariance.clone().symmetric_eigen();
+    let clipped_sqrt_eigenvalues = eig.eigenvalues.map(|v| v.max(0.0).sqrt());
+    Ok(&eig.eigenvectors * DMatrix::from_diagonal(&clipped_sqrt_eigenvalues))
+}
+
+/// Average and longest length (in periods) of consecutive below-peak runs in
+/// a drawdown series.
+fn drawdown_durations(series: &[f64]) -> (f64, u32) {
+    let mut durations = Vec::new();
+    let mut current = 0u32;
+    for &dd in series {
+        if dd > 1e-12 {
+            current += 1;
+        } else if current > 0 {
+            durations.push(current);
+            current = 0;
+        }
+    }
+    if current > 0 {
+        durations.push(current);
+    }
+
+    if durations.is_empty() {
+        (0.0, 0)
+    } else {
+        let average = durations.iter().sum::<u32>() as f64 / durations.len() as f64;
+        let longest = *durations.iter().max().unwrap();
+        (average, longest)
+    }
+}
+
 impl PortfolioAnalyzer {
     pub fn new() -> Self {
         Self {}
@@ -54,13 +251,14 @@ impl PortfolioAnalyzer {
 
     /// Risk-Adjusted Performance Measures
     pub fn sharpe_ratio(&self, returns: &[f64], risk_free_rate: f64) -> Result<f64> {
-        // TODO: Calculate Sharpe ratio for portfolio
-        // - Calculate mean excess return: mean(returns) - risk_free_rate
-        // - Calculate standard deviation of returns
-        // - Sharpe ratio = excess_return / std_deviation
-        // - Annualize if necessary based on return frequency
-        // - Handle edge cases (zero volatility)
-        panic!("TODO: Implement portfolio Sharpe ratio")
+        if returns.len() < 2 {
+            return Err(anyhow::anyhow!("At least 2 returns are required"));
+        }
+        let sigma = std_dev(returns);
+        if sigma < 1e-12 {
+            return Err(anyhow::anyhow!("Zero volatility: Sharpe ratio is undefined"));
+        }
+        Ok((mean(returns) - risk_free_rate) / sigma)
     }
 
     pub fn sortino_ratio(&self, returns: &[f64], risk_free_rate: f64, target_return: f64) -> Result<f64> {
@@ -73,12 +271,7 @@ impl PortfolioAnalyzer {
     }
 
     pub fn treynor_ratio(&self, returns: &[f64], market_returns: &[f64], risk_free_rate: f64) -> Result<f64> {
-        // TODO: Calculate Treynor ratio
-        // - Calculate portfolio beta relative to market
-        // - Calculate excess return over risk-free rate
-        // - Treynor ratio = excess_return / beta
-        // - Handle edge cases (zero beta)
-        panic!("TODO: Implement portfolio Treynor ratio")
+        RiskCalculator::new().treynor_ratio(returns, market_returns, risk_free_rate)
     }
 
     pub fn jensen_alpha(&self, returns: &[f64], market_returns: &[f64], risk_free_rate: f64) -> Result<f64> {
@@ -111,14 +304,120 @@ impl PortfolioAnalyzer {
     }
 
     /// Portfolio Optimization
+    ///
+    /// Maximizes utility w^Tμ - (λ/2)w^TΣw subject to Σw_i = 1, via the
+    /// closed-form Lagrangian solution w = (1/λ)Σ⁻¹(μ - γ1), with γ chosen
+    /// so the weights sum to one.
     pub fn mean_variance_optimization(&self, expected_returns: &[f64], covariance_matrix: &DMatrix<f64>, risk_aversion: f64) -> Result<Vec<f64>> {
-        // TODO: Solve mean-variance optimization problem
-        // - Maximize utility: w^T * μ - (λ/2) * w^T * Σ * w
-        // - Subject to constraint: Σw_i = 1 (fully invested)
-        // - Use quadratic programming solver
-        // - Handle numerical optimization challenges
-        // - Return optimal weight vector
-        panic!("TODO: Implement mean-variance optimization")
+        let n = expected_returns.len();
+        if covariance_matrix.nrows() != n || covariance_matrix.ncols() != n {
+            return Err(anyhow::anyhow!("Covariance matrix dimensions must match expected_returns length"));
+        }
+        if risk_aversion.abs() < 1e-12 {
+            return Err(anyhow::anyhow!("Risk aversion must be non-zero"));
+        }
+
+        let cov_inv = covariance_matrix.clone().try_inverse().ok_or_else(|| anyhow::anyhow!("Covariance matrix is singular"))?;
+        let ones = DVector::from_element(n, 1.0);
+        let mu = DVector::from_column_slice(expected_returns);
+
+        let a = (ones.transpose() * &cov_inv * &ones)[(0, 0)];
+        let b = (ones.transpose() * &cov_inv * &mu)[(0, 0)];
+        let gamma = (b - risk_aversion) / a;
+        let w = (&cov_inv * (&mu - &ones * gamma)) / risk_aversion;
+
+        Ok(w.iter().copied().collect())
+    }
+
+    /// Minimizes portfolio Conditional VaR directly via the Rockafellar-Uryasev
+    /// formulation, rather than the variance proxy used by
+    /// `mean_variance_optimization`. `scenario_returns` holds empirical (or
+    /// simulated) scenario rows with one column per asset; `confidence_level`
+    /// is the CVaR confidence β (e.g. 0.95); `target_return` optionally adds
+    /// a penalty on any shortfall of w·μ below target, using the scenario
+    /// sample mean as μ. The penalty is soft: the returned weights can still
+    /// have w·μ below target.
+    ///
+    /// The RU program minimizes α + (1/((1-β)·S))·Σz_s subject to
+    /// z_s ≥ -(w·r_s) - α, z_s ≥ 0, Σw_i = 1, w ≥ 0. At the optimum
+    /// z_s = max(0, -(w·r_s)-α), so this collapses to an unconstrained-in-z
+    /// convex program over (w, α), solved here by projected subgradient
+    /// descent rather than a general-purpose simplex/interior-point LP solver.
+    pub fn minimize_cvar(&self, scenario_returns: &DMatrix<f64>, confidence_level: f64, target_return: Option<f64>) -> Result<CvarOptimizationResult> {
+        let s = scenario_returns.nrows();
+        let n = scenario_returns.ncols();
+        if s == 0 || n == 0 {
+            return Err(anyhow::anyhow!("Scenario matrix must be non-empty"));
+        }
+        if !(0.0..1.0).contains(&confidence_level) {
+            return Err(anyhow::anyhow!("Confidence level must be in [0, 1)"));
+        }
+
+        let tail_scale = 1.0 / ((1.0 - confidence_level) * s as f64);
+        let asset_means: Vec<f64> = (0..n).map(|j| scenario_returns.column(j).iter().sum::<f64>() / s as f64).collect();
+
+        let mut w = vec![1.0 / n as f64; n];
+        let mut alpha = 0.0f64;
+        let step = 0.05;
+        let return_floor_penalty = 100.0;
+
+        for _ in 0..2000 {
+            let mut w_grad = vec![0.0; n];
+            let mut alpha_grad = 1.0;
+
+            for s_idx in 0..s {
+                let scenario = scenario_returns.row(s_idx);
+                let port_return: f64 = (0..n).map(|i| w[i] * scenario[i]).sum();
+                let z = -port_return - alpha;
+                if z > 0.0 {
+                    alpha_grad -= tail_scale;
+                    for i in 0..n {
+                        w_grad[i] -= tail_scale * scenario[i];
+                    }
+                }
+            }
+
+            if let Some(target) = target_return {
+                let port_mean: f64 = w.iter().zip(&asset_means).map(|(wi, m)| wi * m).sum();
+                if port_mean < target {
+                    let shortfall = target - port_mean;
+                    for i in 0..n {
+                        w_grad[i] -= return_floor_penalty * shortfall * asset_means[i];
+                    }
+                }
+            }
+
+            for i in 0..n {
+                w[i] -= step * w_grad[i];
+            }
+            alpha -= step * alpha_grad;
+
+            // Project onto the feasible set: long-only clamp then
+            // renormalize to the unit-budget constraint.
+            for wi in w.iter_mut() {
+                if *wi < 0.0 {
+                    *wi = 0.0;
+                }
+            }
+            let total: f64 = w.iter().sum();
+            if total.abs() > 1e-12 {
+                for wi in w.iter_mut() {
+                    *wi /= total;
+                }
+            }
+        }
+
+        let cvar = alpha
+            + tail_scale
+                * (0..s)
+                    .map(|s_idx| {
+                        let scenario = scenario_returns.row(s_idx);
+                        let port_return: f64 = (0..n).map(|i| w[i] * scenario[i]).sum();
+                        (-port_return - alpha).max(0.0)
+                    })
+                    .sum::<f64>();
+
+        Ok(CvarOptimizationResult { weights: w, var: alpha, cvar })
     }
 
     pub fn efficient_frontier(&self, expected_returns: &[f64], covariance_matrix: &DMatrix<f64>, num_points: usize) -> Result<(Vec<f64>, Vec<f64>)> {
@@ -131,14 +430,69 @@ impl PortfolioAnalyzer {
         panic!("TODO: Implement efficient frontier calculation")
     }
 
-    pub fn black_litterman_optimization(&self, market_weights: &[f64], expected_returns: &[f64], covariance_matrix: &DMatrix<f64>, tau: f64) -> Result<Vec<f64>> {
-        // TODO: Implement Black-Litterman model
-        // - Start with market equilibrium returns
-        // - Incorporate investor views with confidence levels
-        // - Update expected returns using Bayesian approach
-        // - Calculate optimal portfolio weights
-        // - Handle view incorporation and confidence weighting
-        panic!("TODO: Implement Black-Litterman optimization")
+    /// Black-Litterman blended-return portfolio: reverse-optimizes implied
+    /// equilibrium returns Π = λΣw_mkt from the market-cap weights, blends
+    /// them with investor `views` via the posterior
+    /// E[R] = [(τΣ)⁻¹ + PᵀΩ⁻¹P]⁻¹[(τΣ)⁻¹Π + PᵀΩ⁻¹Q], then feeds the
+    /// posterior returns into `mean_variance_optimization` for the final
+    /// weights. When `omega` is omitted, each view's uncertainty is derived
+    /// from its stated `confidence` as (P·τΣ·Pᵀ)_ii / confidence_i, so a
+    /// confidence of 1.0 recovers the standard He-Litterman default.
+    pub fn black_litterman_optimization(&self, market_weights: &[f64], covariance_matrix: &DMatrix<f64>, risk_aversion: f64, tau: f64, views: &[BlackLittermanView], omega: Option<&DMatrix<f64>>) -> Result<Vec<f64>> {
+        let n = market_weights.len();
+        if covariance_matrix.nrows() != n || covariance_matrix.ncols() != n {
+            return Err(anyhow::anyhow!("Covariance matrix dimensions must match market_weights length"));
+        }
+        if views.is_empty() {
+            return Err(anyhow::anyhow!("At least one view is required"));
+        }
+        for view in views {
+            if view.asset_weights.len() != n {
+                return Err(anyhow::anyhow!("Each view's asset_weights must match market_weights length"));
+            }
+        }
+        let k = views.len();
+
+        let w_mkt = DVector::from_column_slice(market_weights);
+        let pi = (covariance_matrix * &w_mkt) * risk_aversion;
+
+        let mut p = DMatrix::from_element(k, n, 0.0);
+        let mut q = DVector::from_element(k, 0.0);
+        for (row, view) in views.iter().enumerate() {
+            for col in 0..n {
+                p[(row, col)] = view.asset_weights[col];
+            }
+            q[row] = view.expected_value;
+        }
+
+        let tau_sigma = covariance_matrix * tau;
+
+        let derived_omega;
+        let omega_matrix: &DMatrix<f64> = match omega {
+            Some(explicit) => explicit,
+            None => {
+                let tau_sigma_pt = &tau_sigma * p.transpose();
+                let p_tau_sigma_pt = &p * tau_sigma_pt;
+                let mut built = DMatrix::from_element(k, k, 0.0);
+                for i in 0..k {
+                    let confidence = views[i].confidence.max(1e-6);
+                    built[(i, i)] = p_tau_sigma_pt[(i, i)] / confidence;
+                }
+                derived_omega = built;
+                &derived_omega
+            }
+        };
+
+        let tau_sigma_inv = tau_sigma.clone().try_inverse().ok_or_else(|| anyhow::anyhow!("τΣ is singular"))?;
+        let omega_inv = omega_matrix.clone().try_inverse().ok_or_else(|| anyhow::anyhow!("Ω is singular"))?;
+
+        let posterior_precision = &tau_sigma_inv + p.transpose() * &omega_inv * &p;
+        let posterior_precision_inv = posterior_precision.try_inverse().ok_or_else(|| anyhow::anyhow!("Posterior precision matrix is singular"))?;
+        let posterior_rhs = &tau_sigma_inv * &pi + p.transpose() * &omega_inv * &q;
+        let posterior_returns = posterior_precision_inv * posterior_rhs;
+
+        let blended_returns: Vec<f64> = posterior_returns.iter().copied().collect();
+        self.mean_variance_optimization(&blended_returns, covariance_matrix, risk_aversion)
     }
 
     /// Risk Management
@@ -160,22 +514,108 @@ impl PortfolioAnalyzer {
         panic!("TODO: Implement component VaR calculation")
     }
 
+    /// Central third co-moment tensor of `returns` (T observations × N
+    /// assets), flattened to the n×n² layout `M3[(i, j*n+k)] =
+    /// mean((r_i-μ_i)(r_j-μ_j)(r_k-μ_k))`. Aggregating to a portfolio's
+    /// skewness is then `s_p = wᵀM3(w⊗w) / σ_p³` (see `modified_var`).
+    pub fn coskewness_matrix(&self, returns: &DMatrix<f64>) -> Result<DMatrix<f64>> {
+        let t = returns.nrows();
+        let n = returns.ncols();
+        if t < 2 || n == 0 {
+            return Err(anyhow::anyhow!("Return matrix must have at least 2 rows and 1 column"));
+        }
+
+        let means: Vec<f64> = (0..n).map(|j| returns.column(j).iter().sum::<f64>() / t as f64).collect();
+        let mut m3 = DMatrix::from_element(n, n * n, 0.0);
+        for i in 0..n {
+            for j in 0..n {
+                for k in 0..n {
+                    let sum: f64 = (0..t).map(|row| (returns[(row, i)] - means[i]) * (returns[(row, j)] - means[j]) * (returns[(row, k)] - means[k])).sum();
+                    m3[(i, j * n + k)] = sum / t as f64;
+                }
+            }
+        }
+        Ok(m3)
+    }
+
+    /// Central fourth co-moment tensor of `returns`, flattened to the n×n³
+    /// layout `M4[(i, j*n*n+k*n+l)] = mean((r_i-μ_i)(r_j-μ_j)(r_k-μ_k)(r_l-μ_l))`.
+    /// Aggregating to a portfolio's kurtosis is `k_p = wᵀM4(w⊗w⊗w) / σ_p⁴`.
+    pub fn cokurtosis_matrix(&self, returns: &DMatrix<f64>) -> Result<DMatrix<f64>> {
+        let t = returns.nrows();
+        let n = returns.ncols();
+        if t < 2 || n == 0 {
+            return Err(anyhow::anyhow!("Return matrix must have at least 2 rows and 1 column"));
+        }
+
+        let means: Vec<f64> = (0..n).map(|j| returns.column(j).iter().sum::<f64>() / t as f64).collect();
+        let mut m4 = DMatrix::from_element(n, n * n * n, 0.0);
+        for i in 0..n {
+            for j in 0..n {
+                for k in 0..n {
+                    for l in 0..n {
+                        let sum: f64 = (0..t)
+                            .map(|row| (returns[(row, i)] - means[i]) * (returns[(row, j)] - means[j]) * (returns[(row, k)] - means[k]) * (returns[(row, l)] - means[l]))
+                            .sum();
+                        m4[(i, j * n * n + k * n + l)] = sum / t as f64;
+                    }
+                }
+            }
+        }
+        Ok(m4)
+    }
+
+    /// Modified VaR via the Cornish-Fisher expansion, correcting the Gaussian
+    /// quantile for the portfolio return series' own sample skewness S and
+    /// excess kurtosis K rather than assuming normality.
+    pub fn modified_var(&self, returns: &[f64], confidence_level: f64) -> Result<f64> {
+        if returns.len() < 3 {
+            return Err(anyhow::anyhow!("At least 3 returns are required to estimate skew and kurtosis"));
+        }
+        if !(0.0..1.0).contains(&confidence_level) {
+            return Err(anyhow::anyhow!("Confidence level must be in [0, 1)"));
+        }
+
+        let mu = mean(returns);
+        let sigma = std_dev(returns);
+        let s = skewness(returns);
+        let k = excess_kurtosis(returns);
+
+        let normal = Normal::new(0.0, 1.0).map_err(|e| anyhow::anyhow!("Failed to construct normal distribution: {}", e))?;
+        let z = normal.inverse_cdf(1.0 - confidence_level);
+        let z_cf = cornish_fisher_quantile(z, s, k);
+
+        Ok(-(mu + z_cf * sigma))
+    }
+
+    /// Modified Sharpe ratio: divides the excess return by `modified_var`
+    /// instead of the raw standard deviation, penalizing non-normal tail risk.
+    pub fn modified_sharpe_ratio(&self, returns: &[f64], risk_free_rate: f64, confidence_level: f64) -> Result<f64> {
+        let modified_var = self.modified_var(returns, confidence_level)?;
+        if modified_var.abs() < 1e-12 {
+            return Err(anyhow::anyhow!("Zero modified VaR: modified Sharpe ratio is undefined"));
+        }
+
+        let excess_return = mean(returns) - risk_free_rate;
+        Ok(excess_return / modified_var)
+    }
+
     pub fn maximum_drawdown(&self, portfolio_values: &[f64]) -> Result<f64> {
-        // TODO: Calculate maximum drawdown of portfolio
-        // - Track running maximum (peak) portfolio value
-        // - Calculate drawdown at each point
-        // - Find maximum drawdown over entire period
-        // - Return as positive percentage
-        panic!("TODO: Implement maximum drawdown calculation")
+        if portfolio_values.is_empty() {
+            return Err(anyhow::anyhow!("Portfolio values must not be empty"));
+        }
+        Ok(drawdown_series(portfolio_values).into_iter().fold(0.0, f64::max))
     }
 
     pub fn tracking_error(&self, portfolio_returns: &[f64], benchmark_returns: &[f64]) -> Result<f64> {
-        // TODO: Calculate tracking error vs benchmark
-        // - Calculate excess returns: portfolio - benchmark
-        // - Calculate standard deviation of excess returns
-        // - Annualize if necessary
-        // - Return tracking error
-        panic!("TODO: Implement tracking error calculation")
+        if portfolio_returns.len() != benchmark_returns.len() {
+            return Err(anyhow::anyhow!("portfolio_returns and benchmark_returns must have the same length"));
+        }
+        if portfolio_returns.len() < 2 {
+            return Err(anyhow::anyhow!("At least 2 returns are required"));
+        }
+        let active: Vec<f64> = portfolio_returns.iter().zip(benchmark_returns).map(|(p, b)| p - b).collect();
+        Ok(std_dev(&active))
     }
 
     /// Portfolio Rebalancing
@@ -217,6 +657,121 @@ impl PortfolioAnalyzer {
         panic!("TODO: Implement factor exposure analysis")
     }
 
+    /// Fits the time-series factor model R_p = α + B·F + ε by OLS, returning
+    /// (alpha, factor loadings B, residual variance σ_ε²). `factor_returns`
+    /// has one row per time period and one column per factor.
+    fn fit_factor_model(&self, portfolio_returns: &[f64], factor_returns: &DMatrix<f64>) -> Result<(f64, Vec<f64>, f64)> {
+        let t = factor_returns.nrows();
+        let k = factor_returns.ncols();
+        if portfolio_returns.len() != t {
+            return Err(anyhow::anyhow!("Portfolio returns length must match factor_returns row count"));
+        }
+        if t <= k {
+            return Err(anyhow::anyhow!("Need more observations than factors to fit the model"));
+        }
+
+        let mut design = DMatrix::from_element(t, k + 1, 0.0);
+        for row in 0..t {
+            design[(row, 0)] = 1.0;
+            for col in 0..k {
+                design[(row, col + 1)] = factor_returns[(row, col)];
+            }
+        }
+        let y = DVector::from_column_slice(portfolio_returns);
+
+        let xtx = design.transpose() * &design;
+        let xtx_inv = xtx.try_inverse().ok_or_else(|| anyhow::anyhow!("Factor design matrix is singular"))?;
+        let coeffs = xtx_inv * design.transpose() * &y;
+
+        let alpha = coeffs[0];
+        let betas: Vec<f64> = (0..k).map(|i| coeffs[i + 1]).collect();
+
+        let fitted = &design * &coeffs;
+        let residual_sum_sq: f64 = (0..t).map(|row| (y[row] - fitted[row]).powi(2)).sum();
+        let residual_variance = residual_sum_sq / (t - k - 1).max(1) as f64;
+
+        Ok((alpha, betas, residual_variance))
+    }
+
+    /// Euler risk decomposition shared by `factor_sd_decomposition`,
+    /// `factor_var_decomposition`, and `factor_es_decomposition`: fits the
+    /// factor model, builds the factor covariance Σ_F from `factor_returns`,
+    /// and returns (per-factor volatility components, residual component,
+    /// total portfolio volatility) with the components summing to the total.
+    fn factor_sd_components(&self, portfolio_returns: &[f64], factor_returns: &DMatrix<f64>) -> Result<(Vec<f64>, f64, f64)> {
+        let k = factor_returns.ncols();
+        let (_, betas, residual_variance) = self.fit_factor_model(portfolio_returns, factor_returns)?;
+
+        let t = factor_returns.nrows();
+        let factor_means: Vec<f64> = (0..k).map(|j| factor_returns.column(j).iter().sum::<f64>() / t as f64).collect();
+        let mut sigma_f = DMatrix::from_element(k, k, 0.0);
+        for i in 0..k {
+            for j in 0..k {
+                let cov: f64 = (0..t).map(|row| (factor_returns[(row, i)] - factor_means[i]) * (factor_returns[(row, j)] - factor_means[j])).sum::<f64>() / (t - 1) as f64;
+                sigma_f[(i, j)] = cov;
+            }
+        }
+
+        let b = DVector::from_column_slice(&betas);
+        let sigma_f_b = &sigma_f * &b;
+        let systematic_variance = (b.transpose() * &sigma_f_b)[(0, 0)];
+        let sigma_p = (systematic_variance + residual_variance).max(0.0).sqrt();
+        if sigma_p < 1e-12 {
+            return Ok((vec![0.0; k], 0.0, 0.0));
+        }
+
+        let components: Vec<f64> = (0..k).map(|i| betas[i] * sigma_f_b[i] / sigma_p).collect();
+        let residual_component = residual_variance / sigma_p;
+
+        Ok((components, residual_component, sigma_p))
+    }
+
+    /// Attributes portfolio return volatility to each systematic factor plus
+    /// residual, via Euler allocation of σ_p = sqrt(bᵀΣ_Fb + σ_ε²).
+    pub fn factor_sd_decomposition(&self, portfolio_returns: &[f64], factor_returns: &DMatrix<f64>, factor_names: &[String]) -> Result<HashMap<String, f64>> {
+        if factor_names.len() != factor_returns.ncols() {
+            return Err(anyhow::anyhow!("factor_names length must match factor_returns column count"));
+        }
+        let (components, residual_component, _) = self.factor_sd_components(portfolio_returns, factor_returns)?;
+
+        let mut result = HashMap::new();
+        for (name, component) in factor_names.iter().zip(components) {
+            result.insert(name.clone(), component);
+        }
+        result.insert("residual".to_string(), residual_component);
+        Ok(result)
+    }
+
+    /// Attributes portfolio VaR to each systematic factor plus residual by
+    /// scaling each factor's volatility contribution by the Gaussian quantile
+    /// z_β at `confidence_level`.
+    pub fn factor_var_decomposition(&self, portfolio_returns: &[f64], factor_returns: &DMatrix<f64>, factor_names: &[String], confidence_level: f64) -> Result<HashMap<String, f64>> {
+        let normal = Normal::new(0.0, 1.0).map_err(|e| anyhow::anyhow!("Failed to construct normal distribution: {}", e))?;
+        let z_beta = normal.inverse_cdf(confidence_level);
+
+        let mut result = self.factor_sd_decomposition(portfolio_returns, factor_returns, factor_names)?;
+        for value in result.values_mut() {
+            *value *= z_beta;
+        }
+        Ok(result)
+    }
+
+    /// Attributes portfolio Expected Shortfall to each systematic factor plus
+    /// residual by scaling each factor's volatility contribution by
+    /// φ(z_β)/(1-β) at `confidence_level`.
+    pub fn factor_es_decomposition(&self, portfolio_returns: &[f64], factor_returns: &DMatrix<f64>, factor_names: &[String], confidence_level: f64) -> Result<HashMap<String, f64>> {
+        let normal = Normal::new(0.0, 1.0).map_err(|e| anyhow::anyhow!("Failed to construct normal distribution: {}", e))?;
+        let z_beta = normal.inverse_cdf(confidence_level);
+        let phi_z = (-0.5 * z_beta.powi(2)).exp() / (2.0 * std::f64::consts::PI).sqrt();
+        let es_scale = phi_z / (1.0 - confidence_level);
+
+        let mut result = self.factor_sd_decomposition(portfolio_returns, factor_returns, factor_names)?;
+        for value in result.values_mut() {
+            *value *= es_scale;
+        }
+        Ok(result)
+    }
+
     /// Performance Measurement
     pub fn time_weighted_return(&self, portfolio_values: &[f64], cash_flows: &[f64], dates: &[DateTime<Utc>]) -> Result<f64> {
         // TODO: Calculate time-weighted rate of return
@@ -282,13 +837,170 @@ impl PortfolioAnalyzer {
         panic!("TODO: Implement market cap weighted portfolio")
     }
 
-    pub fn risk_budgeting_portfolio(&self, risk_budgets: &[f64], covariance_matrix: &DMatrix<f64>) -> Result<Vec<f64>> {
-        // TODO: Create risk budgeting portfolio
-        // - Allocate risk (not capital) according to specified budgets
-        // - Solve for weights such that component risks match budgets
-        // - Use iterative optimization algorithm
-        // - Validate risk budgets sum to 1.0
-        panic!("TODO: Implement risk budgeting portfolio construction")
+    /// Risk-parity solver: finds w > 0 minimizing f(w) = ½wᵀΣw - Σbᵢln(wᵢ),
+    /// whose stationarity condition wᵢ(Σw)ᵢ = bᵢ(wᵀΣw) makes each asset's
+    /// risk contribution match its target budget bᵢ. Solved by Newton's
+    /// method on the log-barrier objective (gradient Σw - b/w, Hessian
+    /// Σ + diag(b/w²)) with a positivity-preserving damped step, then
+    /// renormalized so Σwᵢ = 1. Equal budgets bᵢ = 1/n recover the classic
+    /// equal-risk-contribution portfolio.
+    pub fn risk_budgeting_portfolio(&self, risk_budgets: &[f64], covariance_matrix: &DMatrix<f64>) -> Result<RiskBudgetingResult> {
+        let n = risk_budgets.len();
+        if covariance_matrix.nrows() != n || covariance_matrix.ncols() != n {
+            return Err(anyhow::anyhow!("Covariance matrix dimensions must match risk_budgets length"));
+        }
+        if risk_budgets.iter().any(|&b| b <= 0.0) {
+            return Err(anyhow::anyhow!("Risk budgets must all be positive"));
+        }
+        let budget_sum: f64 = risk_budgets.iter().sum();
+        if (budget_sum - 1.0).abs() > 1e-6 {
+            return Err(anyhow::anyhow!("Risk budgets must sum to 1.0"));
+        }
+
+        // Small ridge term to keep the Hessian well-conditioned for
+        // near-singular covariance matrices.
+        let mut sigma = covariance_matrix.clone();
+        for i in 0..n {
+            sigma[(i, i)] += 1e-8;
+        }
+
+        let mut w = vec![1.0 / n as f64; n];
+        for _ in 0..100 {
+            let w_vec = DVector::from_column_slice(&w);
+            let sigma_w = &sigma * &w_vec;
+
+            let grad: Vec<f64> = (0..n).map(|i| sigma_w[i] - risk_budgets[i] / w[i]).collect();
+            let grad_norm = grad.iter().map(|g| g * g).sum::<f64>().sqrt();
+            if grad_norm < 1e-10 {
+                break;
+            }
+
+            let mut hessian = sigma.clone();
+            for i in 0..n {
+                hessian[(i, i)] += risk_budgets[i] / (w[i] * w[i]);
+            }
+
+            let hessian_inv = match hessian.try_inverse() {
+                Some(inv) => inv,
+                None => break,
+            };
+            let grad_vec = DVector::from_column_slice(&grad);
+            let delta = hessian_inv * (-grad_vec);
+
+            // Damped Newton step: shrink until every weight stays positive.
+            let mut step_size = 1.0;
+            loop {
+                let candidate: Vec<f64> = w.iter().zip(delta.iter()).map(|(wi, di)| wi + step_size * di).collect();
+                if candidate.iter().all(|&wi| wi > 1e-10) {
+                    w = candidate;
+                    break;
+                }
+                step_size *= 0.5;
+                if step_size < 1e-8 {
+                    break;
+                }
+            }
+        }
+
+        let total: f64 = w.iter().sum();
+        if total.abs() > 1e-12 {
+            for wi in w.iter_mut() {
+                *wi /= total;
+            }
+        }
+
+        let w_vec = DVector::from_column_slice(&w);
+        let sigma_w = covariance_matrix * &w_vec;
+        let portfolio_variance = (w_vec.transpose() * &sigma_w)[(0, 0)];
+        let risk_contributions: Vec<f64> = if portfolio_variance > 1e-12 {
+            (0..n).map(|i| w[i] * sigma_w[i] / portfolio_variance).collect()
+        } else {
+            vec![0.0; n]
+        };
+
+        Ok(RiskBudgetingResult { weights: w, risk_contributions })
+    }
+
+    /// Brandt-Santa-Clara-Valkanov parametric portfolio policy for large
+    /// cross-sections where estimating a full covariance matrix is
+    /// infeasible. Weights are parametrized as
+    /// w_it = w_bar_it + (1/N_t)·θᵀ·x̂_it, where `benchmark_weights` supplies
+    /// w_bar_it, `characteristics[t]` holds the cross-sectionally
+    /// standardized characteristics x̂_it (N_t assets × K characteristics)
+    /// for period t, and θ is a single small coefficient vector shared
+    /// across assets and time. θ is fit by gradient-ascent maximization of
+    /// average CRRA utility (1/T)Σ_t u(Σ_i w_it·(1+r_{i,t+1})) over the
+    /// panel of `forward_returns`.
+    pub fn parametric_portfolio_policy(&self, characteristics: &[DMatrix<f64>], benchmark_weights: &[Vec<f64>], forward_returns: &[Vec<f64>], risk_aversion: f64) -> Result<ParametricPolicyResult> {
+        let num_periods = characteristics.len();
+        if num_periods == 0 {
+            return Err(anyhow::anyhow!("At least one period is required"));
+        }
+        if benchmark_weights.len() != num_periods || forward_returns.len() != num_periods {
+            return Err(anyhow::anyhow!("characteristics, benchmark_weights, and forward_returns must have the same number of periods"));
+        }
+        let k = characteristics[0].ncols();
+        for t in 0..num_periods {
+            let n_t = characteristics[t].nrows();
+            if benchmark_weights[t].len() != n_t || forward_returns[t].len() != n_t {
+                return Err(anyhow::anyhow!("Period {} has mismatched asset counts across inputs", t));
+            }
+            if characteristics[t].ncols() != k {
+                return Err(anyhow::anyhow!("All periods must share the same characteristic count"));
+            }
+        }
+
+        let crra_utility = |gross_return: f64| -> f64 {
+            if gross_return <= 1e-6 {
+                return -1.0e6 + gross_return;
+            }
+            if (risk_aversion - 1.0).abs() < 1e-8 {
+                gross_return.ln()
+            } else {
+                gross_return.powf(1.0 - risk_aversion) / (1.0 - risk_aversion)
+            }
+        };
+
+        let period_weights = |theta: &[f64], t: usize| -> Vec<f64> {
+            let n_t = characteristics[t].nrows();
+            (0..n_t)
+                .map(|i| {
+                    let tilt: f64 = (0..k).map(|j| theta[j] * characteristics[t][(i, j)]).sum();
+                    benchmark_weights[t][i] + tilt / n_t as f64
+                })
+                .collect()
+        };
+
+        let average_utility = |theta: &[f64]| -> f64 {
+            let total: f64 = (0..num_periods)
+                .map(|t| {
+                    let weights = period_weights(theta, t);
+                    let gross_return: f64 = weights.iter().zip(&forward_returns[t]).map(|(w, r)| w * (1.0 + r)).sum();
+                    crra_utility(gross_return)
+                })
+                .sum();
+            total / num_periods as f64
+        };
+
+        let mut theta = vec![0.0; k];
+        let step = 0.1;
+        let epsilon = 1e-5;
+        for _ in 0..500 {
+            let base = average_utility(&theta);
+            let mut grad = vec![0.0; k];
+            for j in 0..k {
+                let mut perturbed = theta.clone();
+                perturbed[j] += epsilon;
+                grad[j] = (average_utility(&perturbed) - base) / epsilon;
+            }
+            for j in 0..k {
+                theta[j] += step * grad[j];
+            }
+        }
+
+        let weights: Vec<Vec<f64>> = (0..num_periods).map(|t| period_weights(&theta, t)).collect();
+
+        Ok(ParametricPolicyResult { theta, weights })
     }
 
     /// Advanced Portfolio Metrics
@@ -320,13 +1032,76 @@ impl PortfolioAnalyzer {
     }
 
     pub fn omega_ratio(&self, returns: &[f64], threshold: f64) -> Result<f64> {
-        // TODO: Calculate Omega ratio
-        // - Separate returns above and below threshold
-        // - Calculate sum of excess returns above threshold
-        // - Calculate sum of shortfall below threshold
-        // - Omega = sum_gains / sum_losses
-        // - Handle edge cases (no gains or no losses)
-        panic!("TODO: Implement Omega ratio calculation")
+        RiskCalculator::new().omega_ratio(returns, threshold)
+    }
+
+    /// Full performance tear-sheet in a single call, reusing
+    /// `maximum_drawdown`, `tracking_error`, and `sharpe_ratio` for the
+    /// metrics they already cover. `periods_per_year` annualizes return and
+    /// volatility (e.g. 252 for daily, 12 for monthly).
+    pub fn performance_summary(&self, returns: &[f64], benchmark_returns: Option<&[f64]>, risk_free_rate: f64, periods_per_year: f64) -> Result<PerformanceSummary> {
+        if returns.len() < 2 {
+            return Err(anyhow::anyhow!("At least 2 returns are required"));
+        }
+
+        let mut values = Vec::with_capacity(returns.len() + 1);
+        values.push(1.0);
+        for r in returns {
+            values.push(values.last().unwrap() * (1.0 + r));
+        }
+
+        let max_drawdown = self.maximum_drawdown(&values)?;
+        let series = drawdown_series(&values);
+        let pain_index = series.iter().sum::<f64>() / series.len() as f64;
+        let (average_drawdown_duration, longest_drawdown_duration) = drawdown_durations(&series);
+
+        let total_return = values.last().unwrap() - 1.0;
+        let annualized_return = (1.0 + total_return).powf(periods_per_year / returns.len() as f64) - 1.0;
+        let annualized_volatility = std_dev(returns) * periods_per_year.sqrt();
+
+        let calmar_ratio = if max_drawdown > 1e-12 { annualized_return / max_drawdown } else { 0.0 };
+        let pain_ratio = if pain_index > 1e-12 { annualized_return / pain_index } else { 0.0 };
+
+        let information_ratio = match benchmark_returns {
+            Some(bench) => {
+                if bench.len() != returns.len() {
+                    return Err(anyhow::anyhow!("benchmark_returns must have the same length as returns"));
+                }
+                let active: Vec<f64> = returns.iter().zip(bench).map(|(p, b)| p - b).collect();
+                let te = self.tracking_error(returns, bench)?;
+                if te > 1e-12 {
+                    Some((mean(&active) * periods_per_year) / (te * periods_per_year.sqrt()))
+                } else {
+                    None
+                }
+            }
+            None => None,
+        };
+
+        let risk_calc = RiskCalculator::new();
+        let kelly_fraction = risk_calc.kelly_ratio(returns, risk_free_rate).unwrap_or(0.0);
+        let adjusted_sharpe = risk_calc.adjusted_sharpe_ratio(returns).unwrap_or(0.0);
+
+        let target = risk_free_rate;
+        let upside_sum = returns.iter().map(|r| (r - target).max(0.0)).sum::<f64>() / returns.len() as f64;
+        let downside_sq = returns.iter().map(|r| (r - target).min(0.0).powi(2)).sum::<f64>() / returns.len() as f64;
+        let downside_dev = downside_sq.sqrt();
+        let upside_potential_ratio = if downside_dev > 1e-12 { upside_sum / downside_dev } else { 0.0 };
+
+        Ok(PerformanceSummary {
+            annualized_return,
+            annualized_volatility,
+            max_drawdown,
+            calmar_ratio,
+            pain_index,
+            pain_ratio,
+            information_ratio,
+            kelly_fraction,
+            adjusted_sharpe,
+            upside_potential_ratio,
+            average_drawdown_duration,
+            longest_drawdown_duration,
+        })
     }
 
     /// Portfolio Stress Testing
@@ -339,12 +1114,389 @@ impl PortfolioAnalyzer {
         panic!("TODO: Implement portfolio stress testing")
     }
 
-    pub fn monte_carlo_simulation(&self, weights: &[f64], expected_returns: &[f64], covariance_matrix: &DMatrix<f64>, num_simulations: usize, time_horizon: usize) -> Result<Vec<f64>> {
-        // TODO: Run Monte Carlo simulation of portfolio returns
-        // - Generate random multivariate normal returns
-        // - Calculate portfolio returns for each simulation
-        // - Compound returns over time horizon
-        // - Return distribution of simulated outcomes
-        panic!("TODO: Implement Monte Carlo portfolio simulation")
+    /// Monte Carlo simulation of compounded portfolio returns. Factors the
+    /// covariance matrix Σ = LLᵀ via Cholesky (falling back to an
+    /// eigenvalue-clipped square root if Σ is only positive-semidefinite),
+    /// draws correlated shocks r = μ + L·z, and geometrically compounds each
+    /// shock over `time_horizon` periods. `distribution` selects between
+    /// Gaussian z and a multivariate Student-t (scaling z by sqrt(ν/χ²_ν) for
+    /// fat tails). Antithetic variates (each z paired with -z) cut variance.
+    /// Returns the outcome distribution alongside its simulated VaR/CVaR at
+    /// `confidence_level` and mean terminal value.
+    pub fn monte_carlo_simulation(&self, weights: &[f64], expected_returns: &[f64], covariance_matrix: &DMatrix<f64>, num_simulations: usize, time_horizon: usize, distribution: ReturnDistribution, confidence_level: f64) -> Result<MonteCarloResult> {
+        let n = weights.len();
+        if expected_returns.len() != n || covariance_matrix.nrows() != n || covariance_matrix.ncols() != n {
+            return Err(anyhow::anyhow!("weights, expected_returns, and covariance_matrix dimensions must agree"));
+        }
+        if num_simulations == 0 || time_horizon == 0 {
+            return Err(anyhow::anyhow!("num_simulations and time_horizon must be greater than zero"));
+        }
+        if !(0.0..1.0).contains(&confidence_level) {
+            return Err(anyhow::anyhow!("Confidence level must be in [0, 1)"));
+        }
+
+        let l = covariance_sqrt(covariance_matrix)?;
+        let mu = DVector::from_column_slice(expected_returns);
+        let w = DVector::from_column_slice(weights);
+
+        let std_normal = Normal::new(0.0, 1.0).map_err(|e| anyhow::anyhow!("Failed to construct normal distribution: {}", e))?;
+        let chi_squared = match distribution {
+            ReturnDistribution::StudentsT { degrees_of_freedom } => {
+                Some(ChiSquared::new(degrees_of_freedom).map_err(|e| anyhow::anyhow!("Failed to construct chi-squared distribution: {}", e))?)
+            }
+            ReturnDistribution::Gaussian => None,
+        };
+
+        // Antithetic variates: every base draw z is paired with -z, so we
+        // only need half as many independent draws.
+        let pairs = num_simulations.div_ceil(2);
+
+        let pair_outcomes: Vec<(f64, f64)> = (0..pairs)
+            .into_par_iter()
+            .map_init(rand::thread_rng, |rng, _| {
+                let base_z: Vec<f64> = (0..n).map(|_| std_normal.sample(rng)).collect();
+                let t_scale = match (&chi_squared, distribution) {
+                    (Some(chi), ReturnDistribution::StudentsT { degrees_of_freedom }) => {
+                        let draw = chi.sample(rng).max(1e-12);
+                        (degrees_of_freedom / draw).sqrt()
+                    }
+                    _ => 1.0,
+                };
+
+                let compound = |z: DVector<f64>| -> f64 {
+                    let shock = &mu + &l * z;
+                    let period_return = (w.transpose() * &shock)[(0, 0)];
+                    (1.0 + period_return).powi(time_horizon as i32) - 1.0
+                };
+
+                let z_pos = DVector::from_iterator(n, base_z.iter().map(|v| v * t_scale));
+                let z_neg = DVector::from_iterator(n, base_z.iter().map(|v| -v * t_scale));
+                (compound(z_pos), compound(z_neg))
+            })
+            .collect();
+
+        let outcomes: Vec<f64> = pair_outcomes.into_iter().flat_map(|(a, b)| [a, b]).take(num_simulations).collect();
+
+        let mean_terminal_value = mean(&outcomes);
+        let tail_quantile = percentile(&outcomes, 1.0 - confidence_level);
+        let simulated_var = -tail_quantile;
+        let tail_losses: Vec<f64> = outcomes.iter().filter(|&&r| r <= tail_quantile).copied().collect();
+        let simulated_cvar = if tail_losses.is_empty() { simulated_var } else { -mean(&tail_losses) };
+
+        Ok(MonteCarloResult { outcomes, mean_terminal_value, simulated_var, simulated_cvar })
+    }
+}
+
+/// A single book position: either the underlying spot itself, or an option
+/// on it. `quantity` is signed - positive for a long position, negative for
+/// a short.
+#[derive(Debug, Clone)]
+pub struct MarginPosition {
+    pub symbol: String,
+    pub quantity: f64,
+    pub instrument: Instrument,
+}
+
+/// The instrument a `MarginPosition` is carrying. Options are valued off the
+/// underlying's spot price via `FinancialCalculator::black_scholes[_put]`.
+#[derive(Debug, Clone, Copy)]
+pub enum Instrument {
+    Spot,
+    Option {
+        strike: f64,
+        time_to_expiry: f64,
+        volatility: f64,
+        is_call: bool,
+    },
+}
+
+/// Per-asset collateral treatment: a haircut applied to long market value
+/// when counting it as collateral, a weight applied to short market value
+/// when counting it as a liability, and the margin rates charged against
+/// gross notional.
+#[derive(Debug, Clone, Copy)]
+pub struct AssetWeight {
+    pub asset_weight: f64,
+    pub liability_weight: f64,
+    pub init_margin_rate: f64,
+    pub maint_margin_rate: f64,
+}
+
+impl Default for AssetWeight {
+    fn default() -> Self {
+        Self {
+            asset_weight: 1.0,
+            liability_weight: 1.0,
+            init_margin_rate: 0.1,
+            maint_margin_rate: 0.05,
+        }
+    }
+}
+
+/// Aggregate portfolio health produced by `PortfolioRisk::health_report`.
+#[derive(Debug, Clone, Copy)]
+pub struct HealthReport {
+    pub net_value: f64,
+    pub init_margin: f64,
+    pub maint_margin: f64,
+    pub is_liquidatable: bool,
+    pub delta: f64,
+    pub gamma: f64,
+    pub vega: f64,
+}
+
+/// Real-time margin monitoring subsystem. Turns the single-instrument
+/// pricing in `FinancialCalculator` into position-level risk aggregation:
+/// net collateral health, margin requirements, Greeks exposure, and a
+/// liquidation-price solver.
+pub struct PortfolioRisk {
+    financial: FinancialCalculator,
+}
+
+impl Default for PortfolioRisk {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PortfolioRisk {
+    pub fn new() -> Self {
+        Self { financial: FinancialCalculator::new() }
+    }
+
+    /// Mark-to-market value of a single position at a given underlying spot
+    /// price (positive for calls/spot, signed by `quantity`).
+    fn position_value(&self, position: &MarginPosition, spot: f64, risk_free_rate: f64) -> Result<f64> {
+        let unit_price = match position.instrument {
+            Instrument::Spot => spot,
+            Instrument::Option { strike, time_to_expiry, volatility, is_call } => {
+                if is_call {
+                    self.financial.black_scholes(spot, strike, time_to_expiry, risk_free_rate, volatility)?
+                } else {
+                    self.financial.black_scholes_put(spot, strike, time_to_expiry, risk_free_rate, volatility)?
+                }
+            }
+        };
+
+        Ok(position.quantity * unit_price)
+    }
+
+    /// Net collateral health at a given underlying spot price: longs count
+    /// at `asset_weight * value`, shorts count as a liability at
+    /// `-liability_weight * |value|`.
+    fn net_value_at(&self, positions: &[MarginPosition], spot: f64, weights: &HashMap<String, AssetWeight>, risk_free_rate: f64) -> Result<f64> {
+        let mut net_value = 0.0;
+        for position in positions {
+            let weight = weights.get(&position.symbol).copied().unwrap_or_default();
+            let value = self.position_value(position, spot, risk_free_rate)?;
+
+            net_value += if value >= 0.0 {
+                weight.asset_weight * value
+            } else {
+                weight.liability_weight * value
+            };
+        }
+        Ok(net_value)
+    }
+
+    /// Aggregate margin health for a book of positions, each priced off the
+    /// corresponding symbol's latest price in `feed`. Also rolls up the
+    /// portfolio's delta/gamma/vega exposure by summing each option's Greeks
+    /// weighted by position size (spot positions contribute delta 1, gamma
+    /// and vega 0).
+    pub fn health_report(
+        &self,
+        positions: &[MarginPosition],
+        feed: &HashMap<String, CacheOptimizedMarketData>,
+        weights: &HashMap<String, AssetWeight>,
+        risk_free_rate: f64,
+    ) -> Result<HealthReport> {
+        if positions.is_empty() {
+            return Err(anyhow::anyhow!("Positions vector must not be empty"));
+        }
+
+        let mut net_value = 0.0;
+        let mut init_margin = 0.0;
+        let mut maint_margin = 0.0;
+        let mut delta = 0.0;
+        let mut gamma = 0.0;
+        let mut vega = 0.0;
+
+        for position in positions {
+            let market_data = feed
+                .get(&position.symbol)
+                .ok_or_else(|| anyhow::anyhow!("No price feed for symbol {}", position.symbol))?;
+            let spot = market_data.price;
+            let weight = weights.get(&position.symbol).copied().unwrap_or_default();
+
+            let value = self.position_value(position, spot, risk_free_rate)?;
+            let gross_notional = value.abs();
+
+            net_value += if value >= 0.0 {
+                weight.asset_weight * value
+            } else {
+                weight.liability_weight * value
+            };
+            init_margin += gross_notional * weight.init_margin_rate;
+            maint_margin += gross_notional * weight.maint_margin_rate;
+
+            match position.instrument {
+                Instrument::Spot => delta += position.quantity,
+                Instrument::Option { strike, time_to_expiry, volatility, is_call } => {
+                    let position_delta = if is_call {
+                        self.financial.delta_call(spot, strike, time_to_expiry, risk_free_rate, volatility)?
+                    } else {
+                        self.financial.delta_put(spot, strike, time_to_expiry, risk_free_rate, volatility)?
+                    };
+                    delta += position.quantity * position_delta;
+                    gamma += position.quantity * self.financial.gamma(spot, strike, time_to_expiry, risk_free_rate, volatility)?;
+                    vega += position.quantity * self.financial.vega(spot, strike, time_to_expiry, risk_free_rate, volatility)?;
+                }
+            }
+        }
+
+        Ok(HealthReport {
+            net_value,
+            init_margin,
+            maint_margin,
+            is_liquidatable: net_value < maint_margin,
+            delta,
+            gamma,
+            vega,
+        })
+    }
+
+    /// Find the underlying spot price at which net collateral health crosses
+    /// zero, via bisection over `[low, high]`. Assumes every position in
+    /// `positions` is written on the same underlying, so a single spot
+    /// parameterizes all of their valuations.
+    pub fn liquidation_price(
+        &self,
+        positions: &[MarginPosition],
+        weights: &HashMap<String, AssetWeight>,
+        risk_free_rate: f64,
+        low: f64,
+        high: f64,
+    ) -> Result<f64> {
+        if low <= 0.0 || high <= low {
+            return Err(anyhow::anyhow!("Invalid bisection bracket [{}, {}]", low, high));
+        }
+
+        let f_low = self.net_value_at(positions, low, weights, risk_free_rate)?;
+        let f_high = self.net_value_at(positions, high, weights, risk_free_rate)?;
+        if f_low.signum() == f_high.signum() {
+            return Err(anyhow::anyhow!("Net health does not cross zero within [{}, {}]", low, high));
+        }
+
+        let mut lo = low;
+        let mut hi = high;
+        for _ in 0..100 {
+            let mid = 0.5 * (lo + hi);
+            let f_mid = self.net_value_at(positions, mid, weights, risk_free_rate)?;
+
+            if f_mid.abs() < 1e-6 {
+                return Ok(mid);
+            }
+            if f_mid.signum() == f_low.signum() {
+                lo = mid;
+            } else {
+                hi = mid;
+            }
+        }
+
+        Ok(0.5 * (lo + hi))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_risk_budgeting_converges_to_equal_risk_contribution() {
+        let analyzer = PortfolioAnalyzer::new();
+        let covariance = DMatrix::from_row_slice(3, 3, &[
+            0.04, 0.01, 0.00,
+            0.01, 0.09, 0.02,
+            0.00, 0.02, 0.16,
+        ]);
+        let budgets = vec![1.0 / 3.0; 3];
+
+        let result = analyzer.risk_budgeting_portfolio(&budgets, &covariance).unwrap();
+
+        let total_weight: f64 = result.weights.iter().sum();
+        assert!((total_weight - 1.0).abs() < 1e-6);
+        assert!(result.weights.iter().all(|&w| w > 0.0));
+
+        for (contribution, budget) in result.risk_contributions.iter().zip(budgets.iter()) {
+            assert!((contribution - budget).abs() < 1e-4, "risk contribution {} did not converge to budget {}", contribution, budget);
+        }
+    }
+
+    #[test]
+    fn test_risk_budgeting_matches_unequal_budgets() {
+        let analyzer = PortfolioAnalyzer::new();
+        let covariance = DMatrix::from_row_slice(2, 2, &[
+            0.10, 0.00,
+            0.00, 0.02,
+        ]);
+        let budgets = vec![0.25, 0.75];
+
+        let result = analyzer.risk_budgeting_portfolio(&budgets, &covariance).unwrap();
+
+        for (contribution, budget) in result.risk_contributions.iter().zip(budgets.iter()) {
+            assert!((contribution - budget).abs() < 1e-4, "risk contribution {} did not converge to budget {}", contribution, budget);
+        }
+    }
+
+    #[test]
+    fn test_risk_budgeting_rejects_budgets_not_summing_to_one() {
+        let analyzer = PortfolioAnalyzer::new();
+        let covariance = DMatrix::from_row_slice(2, 2, &[0.04, 0.0, 0.0, 0.09]);
+        assert!(analyzer.risk_budgeting_portfolio(&[0.5, 0.6], &covariance).is_err());
+    }
+
+    #[test]
+    fn test_monte_carlo_cvar_is_at_least_as_severe_as_var() {
+        let analyzer = PortfolioAnalyzer::new();
+        let weights = vec![0.5, 0.5];
+        let expected_returns = vec![0.02, 0.01];
+        let covariance = DMatrix::from_row_slice(2, 2, &[
+            0.05, 0.01,
+            0.01, 0.03,
+        ]);
+
+        let result = analyzer
+            .monte_carlo_simulation(&weights, &expected_returns, &covariance, 20_000, 1, ReturnDistribution::Gaussian, 0.95)
+            .unwrap();
+
+        assert_eq!(result.outcomes.len(), 20_000);
+        assert!(result.simulated_cvar >= result.simulated_var - 1e-9, "CVaR ({}) should be at least as severe as VaR ({})", result.simulated_cvar, result.simulated_var);
+    }
+
+    #[test]
+    fn test_monte_carlo_students_t_has_fatter_tail_than_gaussian() {
+        let analyzer = PortfolioAnalyzer::new();
+        let weights = vec![1.0];
+        let expected_returns = vec![0.0];
+        let covariance = DMatrix::from_row_slice(1, 1, &[0.04]);
+
+        let gaussian = analyzer
+            .monte_carlo_simulation(&weights, &expected_returns, &covariance, 50_000, 1, ReturnDistribution::Gaussian, 0.99)
+            .unwrap();
+        let students_t = analyzer
+            .monte_carlo_simulation(&weights, &expected_returns, &covariance, 50_000, 1, ReturnDistribution::StudentsT { degrees_of_freedom: 3.0 }, 0.99)
+            .unwrap();
+
+        assert!(students_t.simulated_var > gaussian.simulated_var, "Student-t VaR ({}) should exceed Gaussian VaR ({}) at the same confidence level", students_t.simulated_var, gaussian.simulated_var);
+    }
+
+    #[test]
+    fn test_monte_carlo_rejects_mismatched_dimensions() {
+        let analyzer = PortfolioAnalyzer::new();
+        let covariance = DMatrix::from_row_slice(2, 2, &[0.04, 0.0, 0.0, 0.09]);
+        assert!(analyzer
+            .monte_carlo_simulation(&[1.0], &[0.0, 0.0], &covariance, 100, 1, ReturnDistribution::Gaussian, 0.95)
+            .is_err());
     }
 }
\ No newline at end of file
@@ -1,12 +1,174 @@
 use anyhow::Result;
 use nalgebra::{DMatrix, DVector};
-use statrs::distribution::{ContinuousCDF, Normal};
+use nalgebra::Cholesky;
+use rand::distributions::Distribution;
+use rand::Rng;
+use rayon::prelude::*;
+use statrs::distribution::{ContinuousCDF, Normal, StudentsT};
 use std::collections::HashMap;
 
 pub struct RiskCalculator {
     normal_dist: Normal,
 }
 
+/// Pluggable risk function φ(w) used by `optimize_portfolio`. VaR/CVaR are
+/// evaluated under a normal approximation of the portfolio return.
+#[derive(Debug, Clone, Copy)]
+pub enum RiskMeasure {
+    Variance,
+    MeanAbsoluteDeviation,
+    ValueAtRisk(f64),
+    ConditionalValueAtRisk(f64),
+}
+
+/// Optimization objective for `optimize_portfolio`.
+#[derive(Debug, Clone, Copy)]
+pub enum OptimizationObjective {
+    MaximizeReturn,
+    MinimizeRisk,
+    MaximizeRatio { risk_free_rate: f64 },
+    MaximizeUtility { risk_aversion: f64 },
+}
+
+/// Constraints accepted by `optimize_portfolio`. The budget constraint
+/// (weights sum to 1) is always enforced; `long_only` additionally clamps
+/// weights to be non-negative.
+#[derive(Debug, Clone)]
+pub struct PortfolioConstraints {
+    pub long_only: bool,
+}
+
+impl Default for PortfolioConstraints {
+    fn default() -> Self {
+        Self { long_only: true }
+    }
+}
+
+/// Bootstrap point estimate with its resampling-based standard error and a
+/// percentile confidence interval.
+#[derive(Debug, Clone, Copy)]
+pub struct BootstrapEstimate {
+    pub point_estimate: f64,
+    pub bootstrap_mean: f64,
+    pub standard_error: f64,
+    pub ci_lower: f64,
+    pub ci_upper: f64,
+}
+
+/// Point estimate of a modified (Cornish-Fisher) VaR/ES calculation, carrying
+/// the adjusted quantile alongside the raw Gaussian one so callers can see
+/// how much the non-normality correction moved the estimate.
+#[derive(Debug, Clone, Copy)]
+pub struct ModifiedVarResult {
+    pub var: f64,
+    pub gaussian_z: f64,
+    pub modified_z: f64,
+    pub skewness: f64,
+    pub excess_kurtosis: f64,
+}
+
+/// Sample mean
+fn mean(data: &[f64]) -> f64 {
+    data.iter().sum::<f64>() / data.len() as f64
+}
+
+/// Sample standard deviation (n-1 denominator)
+fn std_dev(data: &[f64]) -> f64 {
+    variance(data).sqrt()
+}
+
+fn variance(data: &[f64]) -> f64 {
+    let m = mean(data);
+    let n = data.len() as f64;
+    data.iter().map(|x| (x - m).powi(2)).sum::<f64>() / (n - 1.0)
+}
+
+/// Sample skewness (Fisher-Pearson, not bias-corrected)
+fn skewness(data: &[f64]) -> f64 {
+    let m = mean(data);
+    let s = std_dev(data);
+    let n = data.len() as f64;
+    if s == 0.0 {
+        return 0.0;
+    }
+    data.iter().map(|x| ((x - m) / s).powi(3)).sum::<f64>() / n
+}
+
+/// Sample excess kurtosis (kurtosis - 3), not bias-corrected
+fn excess_kurtosis(data: &[f64]) -> f64 {
+    let m = mean(data);
+    let s = std_dev(data);
+    let n = data.len() as f64;
+    if s == 0.0 {
+        return 0.0;
+    }
+    data.iter().map(|x| ((x - m) / s).powi(4)).sum::<f64>() / n - 3.0
+}
+
+/// Linear-interpolated percentile of a slice (0.0 <= q <= 1.0). Sorts a copy.
+fn percentile(data: &[f64], q: f64) -> f64 {
+    let mut sorted = data.to_vec();
+    sorted.sort_by(|a, b| a.total_cmp(b));
+    let n = sorted.len();
+    if n == 1 {
+        return sorted[0];
+    }
+    let pos = q * (n - 1) as f64;
+    let lower = pos.floor() as usize;
+    let upper = pos.ceil() as usize;
+    if lower == upper {
+        sorted[lower]
+    } else {
+        let frac = pos - lower as f64;
+        sorted[lower] * (1.0 - frac) + sorted[upper] * frac
+    }
+}
+
+/// Cornish-Fisher expansion of the standard-normal quantile `z`, adjusted for
+/// sample skewness `s` and excess kurtosis `k`. Clamps the adjustment when it
+/// would push the quantile past a 3x widening of the Gaussian one, which is a
+/// sign the expansion has gone non-monotone for extreme S/K.
+fn cornish_fisher_quantile(z: f64, s: f64, k: f64) -> f64 {
+    let z_cf = z
+        + (z.powi(2) - 1.0) / 6.0 * s
+        + (z.powi(3) - 3.0 * z) / 24.0 * k
+        - (2.0 * z.powi(3) - 5.0 * z) / 36.0 * s.powi(2);
+
+    let max_adjustment = z.abs().max(1.0) * 3.0;
+    if (z_cf - z).abs() > max_adjustment {
+        z + max_adjustment.copysign(z_cf - z)
+    } else {
+        z_cf
+    }
+}
+
+/// Matrix square root L of a covariance matrix such that Σ ≈ L·Lᵀ. Prefers
+/// the Cholesky factorization; if Σ is only positive-semidefinite (Cholesky
+/// fails), falls back to an eigen-decomposition square root with negative
+/// eigenvalues clipped to zero.
+fn covariance_sqrt(covariance: &DMatrix<f64>) -> Result<DMatrix<f64>> {
+    if let Some(chol) = Cholesky::new(covariance.clone()) {
+        return Ok(chol.l());
+    }
+
+    let eig = covariance.clone().symmetric_eigen();
+    let clipped_sqrt_eigenvalues = eig.eigenvalues.map(|v| v.max(0.0).sqrt());
+    Ok(&eig.eigenvectors * DMatrix::from_diagonal(&clipped_sqrt_eigenvalues))
+}
+
+/// Kronecker product of two column vectors, flattened in row-major order
+/// (i.e. `result[i*b.len() + j] = a[i] * b[j]`), matching the index
+/// convention used by the flattened co-moment tensors.
+fn kron(a: &DVector<f64>, b: &DVector<f64>) -> DVector<f64> {
+    let mut out = Vec::with_capacity(a.len() * b.len());
+    for ai in a.iter() {
+        for bj in b.iter() {
+            out.push(ai * bj);
+        }
+    }
+    DVector::from_vec(out)
+}
+
 impl RiskCalculator {
     pub fn new() -> Self {
         Self {
@@ -14,94 +176,651 @@ impl RiskCalculator {
         }
     }
 
-    /// Calculate Value at Risk using different methods
+    fn validate_inputs(returns: &[f64], confidence: f64) -> Result<()> {
+        if returns.is_empty() {
+            return Err(anyhow::anyhow!("Returns series must not be empty"));
+        }
+        if !(confidence > 0.0 && confidence < 1.0) {
+            return Err(anyhow::anyhow!("Confidence level must be in (0, 1), got {}", confidence));
+        }
+        Ok(())
+    }
+
+    /// Calculate Value at Risk using historical simulation
     pub fn value_at_risk(&self, returns: &[f64], confidence: f64) -> Result<f64> {
-        // TODO: Implement VaR calculation using historical simulation
-        // - Sort returns in ascending order
-        // - Find the percentile corresponding to confidence level
-        // - Handle edge cases for small sample sizes
-        // - Validate confidence level is between 0 and 1
-        // - Return negative value indicating potential loss
-        // - Add interpolation for non-integer percentile positions
-        panic!("TODO: Implement historical simulation VaR")
+        Self::validate_inputs(returns, confidence)?;
+
+        // VaR at the (1 - confidence) percentile of the return distribution,
+        // reported as a positive loss number.
+        let tail_quantile = percentile(returns, 1.0 - confidence);
+        Ok(-tail_quantile)
     }
 
+    /// Calculate VaR assuming returns are normally distributed
     pub fn parametric_var(&self, returns: &[f64], confidence: f64) -> Result<f64> {
-        // TODO: Implement parametric VaR assuming normal distribution
-        // - Calculate mean and standard deviation of returns
-        // - Use normal distribution quantile function
-        // - Apply confidence level to get z-score
-        // - Calculate VaR = mean + z_score * std_dev
-        // - Validate assumptions of normality
-        // - Handle edge cases for extreme confidence levels
-        panic!("TODO: Implement parametric VaR calculation")
+        Self::validate_inputs(returns, confidence)?;
+        if returns.len() < 2 {
+            return Err(anyhow::anyhow!("Need at least two observations to estimate volatility"));
+        }
+
+        let mu = mean(returns);
+        let sigma = std_dev(returns);
+        let z = self.normal_dist.inverse_cdf(1.0 - confidence);
+
+        Ok(-(mu + z * sigma))
+    }
+
+    /// Modified VaR via the Cornish-Fisher expansion, which corrects the Gaussian
+    /// quantile for sample skewness and excess kurtosis so tail risk from
+    /// non-normal returns isn't understated.
+    pub fn cornish_fisher_var(&self, returns: &[f64], confidence: f64) -> Result<ModifiedVarResult> {
+        Self::validate_inputs(returns, confidence)?;
+        if returns.len() < 4 {
+            return Err(anyhow::anyhow!("Need at least four observations to estimate skew/kurtosis"));
+        }
+
+        let mu = mean(returns);
+        let sigma = std_dev(returns);
+        let s = skewness(returns);
+        let k = excess_kurtosis(returns);
+        let z = self.normal_dist.inverse_cdf(1.0 - confidence);
+
+        let z_cf = cornish_fisher_quantile(z, s, k);
+
+        Ok(ModifiedVarResult {
+            var: -(mu + z_cf * sigma),
+            gaussian_z: z,
+            modified_z: z_cf,
+            skewness: s,
+            excess_kurtosis: k,
+        })
+    }
+
+    /// Modified Expected Shortfall companion to `cornish_fisher_var`: evaluates
+    /// the Gaussian ES formula at the Cornish-Fisher-adjusted quantile.
+    pub fn cornish_fisher_es(&self, returns: &[f64], confidence: f64) -> Result<f64> {
+        let modified = self.cornish_fisher_var(returns, confidence)?;
+        let mu = mean(returns);
+        let sigma = std_dev(returns);
+
+        // Approximate the tail average by the Gaussian ES formula evaluated at
+        // the Cornish-Fisher-adjusted quantile rather than the raw z-score.
+        let alpha = 1.0 - confidence;
+        let phi_z = (-0.5 * modified.modified_z.powi(2)).exp() / (2.0 * std::f64::consts::PI).sqrt();
+        let es = (phi_z / alpha) * sigma - mu;
+        Ok(es)
+    }
+
+    /// Unified VaR entry point: pick the estimator by name so callers don't
+    /// need to know which underlying method implements it.
+    pub fn var(&self, returns: &[f64], confidence: f64, method: &str) -> Result<f64> {
+        match method {
+            "gaussian" => self.parametric_var(returns, confidence),
+            "historical" => self.value_at_risk(returns, confidence),
+            "modified" => self.cornish_fisher_var(returns, confidence).map(|r| r.var),
+            other => Err(anyhow::anyhow!("Unknown VaR method: {}", other)),
+        }
     }
 
+    /// Single-asset Monte Carlo VaR: draw `simulations` i.i.d. normal returns
+    /// and take the empirical VaR of the simulated distribution.
     pub fn monte_carlo_var(&self, mean: f64, std_dev: f64, confidence: f64, simulations: usize) -> Result<f64> {
-        // TODO: Implement Monte Carlo VaR simulation
-        // - Generate random returns using normal distribution
-        // - Run specified number of Monte Carlo simulations
-        // - Calculate empirical distribution of simulated returns
-        // - Find VaR at specified confidence level
-        // - Validate simulation parameters
-        // - Optimize for performance with vectorized operations
-        panic!("TODO: Implement Monte Carlo VaR simulation")
+        if simulations == 0 {
+            return Err(anyhow::anyhow!("simulations must be greater than zero"));
+        }
+        if !(confidence > 0.0 && confidence < 1.0) {
+            return Err(anyhow::anyhow!("Confidence level must be in (0, 1), got {}", confidence));
+        }
+
+        let dist = Normal::new(mean, std_dev)?;
+        let simulated: Vec<f64> = (0..simulations)
+            .into_par_iter()
+            .map_init(rand::thread_rng, |rng, _| dist.sample(rng))
+            .collect();
+
+        self.value_at_risk(&simulated, confidence)
     }
 
-    /// Calculate Expected Shortfall (Conditional VaR)
+    /// Calculate Expected Shortfall (Conditional VaR) via historical simulation
     pub fn expected_shortfall(&self, returns: &[f64], confidence: f64) -> Result<f64> {
-        // TODO: Implement Expected Shortfall calculation
-        // - First calculate VaR at given confidence level
-        // - Find all returns worse than VaR threshold
-        // - Calculate average of tail losses beyond VaR
-        // - Handle cases where no observations exceed VaR
-        // - Validate confidence level and return data
-        // - Return conditional expected loss in tail
-        panic!("TODO: Implement Expected Shortfall calculation")
+        let var = self.value_at_risk(returns, confidence)?;
+
+        let tail_losses: Vec<f64> = returns.iter().copied().filter(|r| -r >= var).collect();
+
+        if tail_losses.is_empty() {
+            return Ok(var);
+        }
+
+        Ok(-mean(&tail_losses))
+    }
+
+    /// Closed-form (analytic) Expected Shortfall under a normal-returns
+    /// assumption: a fast alternative to the historical `expected_shortfall`.
+    pub fn parametric_es(&self, returns: &[f64], confidence: f64) -> Result<f64> {
+        Self::validate_inputs(returns, confidence)?;
+
+        let mu = mean(returns);
+        let sigma = std_dev(returns);
+        let alpha = 1.0 - confidence;
+        let z = self.normal_dist.inverse_cdf(alpha);
+        let phi_z = (-0.5 * z.powi(2)).exp() / (2.0 * std::f64::consts::PI).sqrt();
+
+        Ok((phi_z / alpha) * sigma - mu)
+    }
+
+    /// Fit a Student-t distribution to the return series (method of moments)
+    /// and return (degrees of freedom, location, scale).
+    fn fit_student_t(&self, returns: &[f64]) -> Result<(f64, f64, f64)> {
+        if returns.len() < 4 {
+            return Err(anyhow::anyhow!("Need at least four observations to fit a Student-t distribution"));
+        }
+
+        let mu_t = mean(returns);
+        let sample_var = variance(returns);
+        let k = excess_kurtosis(returns);
+
+        // Method-of-moments fit: excess kurtosis of a Student-t is 6/(nu-4)
+        // for nu > 4, so invert it (clamped to keep nu in a sane, finite range).
+        let nu = if k > 0.0 { (6.0 / k + 4.0).round() } else { 30.0 };
+        let nu = nu.clamp(3.0, 200.0);
+
+        // Sample variance = nu/(nu-2) * sigma_t^2 for nu > 2.
+        let sigma_t = (sample_var * (nu - 2.0) / nu).sqrt();
+
+        Ok((nu, mu_t, sigma_t))
+    }
+
+    /// VaR from a Student-t fit of the return series, which captures heavier
+    /// tails than the Gaussian `parametric_var`.
+    pub fn student_t_var(&self, returns: &[f64], confidence: f64) -> Result<f64> {
+        Self::validate_inputs(returns, confidence)?;
+        let (nu, mu_t, sigma_t) = self.fit_student_t(returns)?;
+
+        let t_dist = StudentsT::new(0.0, 1.0, nu)?;
+        let x_alpha = t_dist.inverse_cdf(1.0 - confidence);
+
+        Ok(-(mu_t + sigma_t * x_alpha))
+    }
+
+    /// Closed-form CVaR from the same Student-t fit used by `student_t_var`.
+    pub fn student_t_cvar(&self, returns: &[f64], confidence: f64) -> Result<f64> {
+        Self::validate_inputs(returns, confidence)?;
+        let (nu, mu_t, sigma_t) = self.fit_student_t(returns)?;
+
+        let alpha = 1.0 - confidence;
+        let t_dist = StudentsT::new(0.0, 1.0, nu)?;
+        let x_alpha = t_dist.inverse_cdf(alpha);
+
+        let t_pdf = |x: f64, nu: f64| -> f64 {
+            let num = statrs::function::gamma::gamma((nu + 1.0) / 2.0);
+            let den = (nu * std::f64::consts::PI).sqrt() * statrs::function::gamma::gamma(nu / 2.0);
+            (num / den) * (1.0 + x.powi(2) / nu).powf(-(nu + 1.0) / 2.0)
+        };
+
+        let cvar = (1.0 / alpha) * (1.0 / (nu - 1.0)) * (nu - 2.0 + x_alpha.powi(2)) * t_pdf(x_alpha, nu) * sigma_t
+            - mu_t;
+
+        Ok(cvar)
+    }
+
+    /// Extreme Value Theory tail-risk estimator: fits a Generalized Pareto
+    /// (power-law) tail to the worst `tail_fraction` of losses via the Hill
+    /// estimator, then inverts the fitted tail to estimate VaR at a deep
+    /// quantile beyond what the empirical sample alone can resolve.
+    pub fn evt_var(&self, returns: &[f64], confidence: f64, tail_fraction: f64) -> Result<f64> {
+        Self::validate_inputs(returns, confidence)?;
+        if !(tail_fraction > 0.0 && tail_fraction < 1.0) {
+            return Err(anyhow::anyhow!("tail_fraction must be in (0, 1), got {}", tail_fraction));
+        }
+
+        // Work with losses (positive = bad) so the Hill estimator's ordering
+        // convention (largest to threshold) lines up with the tail we care about.
+        let mut losses: Vec<f64> = returns.iter().map(|r| -r).collect();
+        losses.sort_by(|a, b| b.total_cmp(a)); // descending: worst loss first
+
+        let k = ((losses.len() as f64 * tail_fraction).ceil() as usize).max(2).min(losses.len() - 1);
+        let threshold = losses[k - 1]; // x_k, the threshold
+
+        if threshold <= 0.0 {
+            return Err(anyhow::anyhow!("Tail threshold is non-positive; increase tail_fraction or sample size"));
+        }
+
+        // Hill estimator of the tail index alpha.
+        let log_ratio_sum: f64 = losses[..k - 1].iter().map(|x| (x / threshold).ln()).sum();
+        if log_ratio_sum <= 0.0 {
+            return Err(anyhow::anyhow!("Degenerate tail sample: all exceedances equal the threshold"));
+        }
+        let alpha = (k as f64 - 1.0) / log_ratio_sum;
+
+        // Invert the fitted Pareto tail: P(X > x) = (k/n) * (x/threshold)^(-alpha)
+        let n = losses.len() as f64;
+        let k_over_n = k as f64 / n;
+        let exceedance_prob = 1.0 - confidence;
+
+        if exceedance_prob >= k_over_n {
+            // Requested quantile is inside the empirical sample; the tail fit
+            // isn't needed (and would extrapolate the wrong direction).
+            return self.value_at_risk(returns, confidence);
+        }
+
+        let var = threshold * (k_over_n / exceedance_prob).powf(1.0 / alpha);
+        Ok(var)
+    }
+
+    /// Expected Shortfall companion to `evt_var`, using the closed-form mean
+    /// of a Pareto tail beyond the fitted VaR: ES = VaR * alpha / (alpha - 1).
+    pub fn evt_es(&self, returns: &[f64], confidence: f64, tail_fraction: f64) -> Result<f64> {
+        let var = self.evt_var(returns, confidence, tail_fraction)?;
+
+        let mut losses: Vec<f64> = returns.iter().map(|r| -r).collect();
+        losses.sort_by(|a, b| b.total_cmp(a));
+        let k = ((losses.len() as f64 * tail_fraction).ceil() as usize).max(2).min(losses.len() - 1);
+        let threshold = losses[k - 1];
+
+        let log_ratio_sum: f64 = losses[..k - 1].iter().map(|x| (x / threshold).ln()).sum();
+        let alpha = (k as f64 - 1.0) / log_ratio_sum;
+
+        if alpha <= 1.0 {
+            return Err(anyhow::anyhow!("Tail index alpha <= 1: Expected Shortfall is infinite under this fit"));
+        }
+
+        Ok(var * alpha / (alpha - 1.0))
+    }
+
+    /// Bootstrap standard error and percentile confidence interval for
+    /// historical VaR, by resampling the return series with replacement.
+    pub fn bootstrap_var(&self, returns: &[f64], confidence: f64, resamples: usize) -> Result<BootstrapEstimate> {
+        self.bootstrap_statistic(returns, resamples, |sample| self.value_at_risk(sample, confidence))
+    }
+
+    /// Bootstrap standard error and percentile confidence interval for
+    /// historical Expected Shortfall.
+    pub fn bootstrap_es(&self, returns: &[f64], confidence: f64, resamples: usize) -> Result<BootstrapEstimate> {
+        self.bootstrap_statistic(returns, resamples, |sample| self.expected_shortfall(sample, confidence))
+    }
+
+    fn bootstrap_statistic<F>(&self, returns: &[f64], resamples: usize, statistic: F) -> Result<BootstrapEstimate>
+    where
+        F: Fn(&[f64]) -> Result<f64>,
+    {
+        if returns.is_empty() {
+            return Err(anyhow::anyhow!("Returns series must not be empty"));
+        }
+        if resamples == 0 {
+            return Err(anyhow::anyhow!("resamples must be greater than zero"));
+        }
+
+        let point_estimate = statistic(returns)?;
+
+        let mut rng = rand::thread_rng();
+        let n = returns.len();
+        let mut estimates = Vec::with_capacity(resamples);
+
+        for _ in 0..resamples {
+            let sample: Vec<f64> = (0..n).map(|_| returns[rng.gen_range(0..n)]).collect();
+            estimates.push(statistic(&sample)?);
+        }
+
+        let bootstrap_mean = mean(&estimates);
+        let standard_error = std_dev(&estimates);
+        let ci_lower = percentile(&estimates, 0.025);
+        let ci_upper = percentile(&estimates, 0.975);
+
+        Ok(BootstrapEstimate {
+            point_estimate,
+            bootstrap_mean,
+            standard_error,
+            ci_lower,
+            ci_upper,
+        })
     }
 
     /// Portfolio risk metrics
+    /// Portfolio VaR under a Gaussian assumption: sqrt(w^T * Σ * w) scaled by
+    /// the confidence-level normal quantile.
     pub fn portfolio_var(&self, weights: &[f64], covariance_matrix: &DMatrix<f64>, confidence: f64) -> Result<f64> {
-        // TODO: Implement portfolio VaR using covariance matrix
-        // - Validate weights sum to 1.0
-        // - Check covariance matrix dimensions match weights
-        // - Calculate portfolio variance: w^T * Σ * w
-        // - Convert variance to standard deviation
-        // - Apply confidence level using normal distribution
-        // - Handle numerical stability issues
-        panic!("TODO: Implement portfolio VaR calculation")
+        let sigma_p = self.portfolio_volatility(weights, covariance_matrix)?;
+        let z = self.normal_dist.inverse_cdf(1.0 - confidence);
+        Ok(-z * sigma_p)
+    }
+
+    fn portfolio_volatility(&self, weights: &[f64], covariance_matrix: &DMatrix<f64>) -> Result<f64> {
+        let n = weights.len();
+        if covariance_matrix.nrows() != n || covariance_matrix.ncols() != n {
+            return Err(anyhow::anyhow!(
+                "Covariance matrix dimensions ({}, {}) do not match weights length {}",
+                covariance_matrix.nrows(),
+                covariance_matrix.ncols(),
+                n
+            ));
+        }
+
+        let w = DVector::from_column_slice(weights);
+        let variance = (w.transpose() * covariance_matrix * &w)[(0, 0)];
+        Ok(variance.max(0.0).sqrt())
     }
 
+    /// Marginal VaR for each asset: d(portfolio VaR)/d(weight_i), via the
+    /// chain rule on the Gaussian portfolio VaR formula.
     pub fn marginal_var(&self, weights: &[f64], covariance_matrix: &DMatrix<f64>, confidence: f64) -> Result<Vec<f64>> {
-        // TODO: Calculate marginal VaR for each asset
-        // - Calculate portfolio VaR first
-        // - Compute partial derivatives of portfolio VaR w.r.t. weights
-        // - Use chain rule for VaR sensitivity
-        // - Return marginal VaR vector for each asset
-        // - Validate mathematical consistency
-        panic!("TODO: Implement marginal VaR calculation")
+        let sigma_p = self.portfolio_volatility(weights, covariance_matrix)?;
+        if sigma_p == 0.0 {
+            return Ok(vec![0.0; weights.len()]);
+        }
+
+        let z = self.normal_dist.inverse_cdf(1.0 - confidence);
+        let w = DVector::from_column_slice(weights);
+        let sigma_w = covariance_matrix * &w;
+
+        // d(sigma_p)/d(w_i) = (Σw)_i / sigma_p; VaR = -z * sigma_p.
+        Ok(sigma_w.iter().map(|cov_i| -z * cov_i / sigma_p).collect())
     }
 
+    /// Component VaR for each asset (marginal VaR * weight); these sum to the
+    /// total portfolio VaR by Euler's homogeneity theorem.
     pub fn component_var(&self, weights: &[f64], covariance_matrix: &DMatrix<f64>, confidence: f64) -> Result<Vec<f64>> {
-        // TODO: Calculate component VaR for each asset
-        // - Calculate marginal VaR for each asset
-        // - Multiply marginal VaR by asset weights
-        // - Ensure components sum to total portfolio VaR
-        // - Handle zero weight positions
-        // - Validate decomposition accuracy
-        panic!("TODO: Implement component VaR calculation")
+        let marginal = self.marginal_var(weights, covariance_matrix, confidence)?;
+        Ok(marginal.iter().zip(weights).map(|(m, w)| m * w).collect())
+    }
+
+    /// Modified (Cornish-Fisher) component/marginal VaR decomposition that
+    /// accounts for portfolio skewness and kurtosis instead of assuming a
+    /// Gaussian return distribution. `coskewness` and `cokurtosis` are the
+    /// flattened n×n² and n×n³ co-moment tensors (row-major over the
+    /// Kronecker-product index) used to compute portfolio skewness
+    /// s_p = wᵀM3(w⊗w)/σ_p³ and kurtosis k_p = wᵀM4(w⊗w⊗w)/σ_p⁴. The
+    /// marginal contributions are the analytic gradient of the Cornish-Fisher
+    /// VaR quantile with respect to weights; by Euler's theorem the
+    /// resulting component VaRs sum to the total modified VaR.
+    pub fn modified_component_var(
+        &self,
+        weights: &[f64],
+        mean_vector: &[f64],
+        covariance: &DMatrix<f64>,
+        coskewness: &DMatrix<f64>,
+        cokurtosis: &DMatrix<f64>,
+        confidence: f64,
+    ) -> Result<(f64, Vec<f64>)> {
+        let n = weights.len();
+        if mean_vector.len() != n || covariance.nrows() != n || covariance.ncols() != n {
+            return Err(anyhow::anyhow!("weights, mean_vector, and covariance dimensions must agree"));
+        }
+        if coskewness.nrows() != n || coskewness.ncols() != n * n {
+            return Err(anyhow::anyhow!("coskewness must be an n x n^2 matrix"));
+        }
+        if cokurtosis.nrows() != n || cokurtosis.ncols() != n * n * n {
+            return Err(anyhow::anyhow!("cokurtosis must be an n x n^3 matrix"));
+        }
+
+        let w = DVector::from_column_slice(weights);
+        let sigma_p = self.portfolio_volatility(weights, covariance)?;
+        if sigma_p < 1e-12 {
+            return Err(anyhow::anyhow!("Degenerate portfolio: zero volatility"));
+        }
+        let mu_p: f64 = weights.iter().zip(mean_vector).map(|(wi, mi)| wi * mi).sum();
+
+        // w⊗w and w⊗w⊗w flattened in the same row-major Kronecker order as
+        // the co-moment tensors.
+        let w_kron_w = kron(&w, &w);
+        let w_kron_w_kron_w = kron(&w_kron_w, &w);
+
+        let m3_w = coskewness * &w_kron_w; // n x 1
+        let s_p_numerator = (w.transpose() * &m3_w)[(0, 0)];
+        let s_p = s_p_numerator / sigma_p.powi(3);
+
+        let m4_w = cokurtosis * &w_kron_w_kron_w; // n x 1
+        let k_p_numerator = (w.transpose() * &m4_w)[(0, 0)];
+        let k_p = k_p_numerator / sigma_p.powi(4);
+
+        let z = self.normal_dist.inverse_cdf(1.0 - confidence);
+        let z_cf = cornish_fisher_quantile(z, s_p, k_p);
+        let modified_var = -(mu_p + z_cf * sigma_p);
+
+        // Analytic gradient of modified VaR w.r.t. weights. We differentiate
+        // through mu_p, sigma_p, s_p, and k_p using the chain rule, then
+        // apply d(z_cf)/d(s_p), d(z_cf)/d(k_p) (z itself is fixed) from the
+        // Cornish-Fisher expansion:
+        //   z_cf = z + (z^2-1)s/6 + (z^3-3z)k/24 - (2z^3-5z)s^2/36
+        let dzcf_ds = (z * z - 1.0) / 6.0 - (2.0 * z.powi(3) - 5.0 * z) * s_p / 18.0;
+        let dzcf_dk = (z.powi(3) - 3.0 * z) / 24.0;
+
+        let sigma_w = covariance * &w; // d(sigma_p)/d(w) direction, n x 1
+        let d_sigma_p = sigma_w.iter().map(|v| v / sigma_p).collect::<Vec<_>>();
+
+        // d(s_p)/d(w_i) = [3*(M3 w⊗w)_i * sigma_p^3 - s_p_numerator*3*sigma_p^2*d(sigma_p)/d(w_i)] / sigma_p^6
+        // using the product/quotient rule on s_p = (wᵀM3(w⊗w)) / sigma_p^3,
+        // with d(wᵀM3(w⊗w))/d(w_i) ≈ 3*(M3(w⊗w))_i for the symmetric tensor.
+        let d_s_numerator: Vec<f64> = m3_w.iter().map(|v| 3.0 * v).collect();
+        let d_k_numerator: Vec<f64> = m4_w.iter().map(|v| 4.0 * v).collect();
+
+        let mut marginal = Vec::with_capacity(n);
+        for i in 0..n {
+            let d_sp_i = d_sigma_p[i];
+            let d_s_p_i = (d_s_numerator[i] * sigma_p.powi(3) - s_p_numerator * 3.0 * sigma_p.powi(2) * d_sp_i) / sigma_p.powi(6);
+            let d_k_p_i = (d_k_numerator[i] * sigma_p.powi(4) - k_p_numerator * 4.0 * sigma_p.powi(3) * d_sp_i) / sigma_p.powi(8);
+
+            let d_zcf_i = dzcf_ds * d_s_p_i + dzcf_dk * d_k_p_i;
+            let d_var_i = -(mean_vector[i] + d_zcf_i * sigma_p + z_cf * d_sp_i);
+            marginal.push(d_var_i);
+        }
+
+        let component: Vec<f64> = marginal.iter().zip(weights).map(|(m, wi)| m * wi).collect();
+        Ok((modified_var, component))
+    }
+
+    /// Risk measure selectable by `optimize_portfolio`, evaluated on the
+    /// portfolio's (mean, variance) under a normal approximation.
+    pub fn portfolio_risk(&self, weights: &[f64], means: &[f64], covariance_matrix: &DMatrix<f64>, measure: RiskMeasure) -> Result<f64> {
+        let sigma_p = self.portfolio_volatility(weights, covariance_matrix)?;
+        let mu_p: f64 = weights.iter().zip(means).map(|(w, m)| w * m).sum();
+
+        Ok(match measure {
+            RiskMeasure::Variance => sigma_p.powi(2),
+            RiskMeasure::MeanAbsoluteDeviation => sigma_p * (2.0 / std::f64::consts::PI).sqrt(),
+            RiskMeasure::ValueAtRisk(confidence) => {
+                let z = self.normal_dist.inverse_cdf(1.0 - confidence);
+                -(mu_p + z * sigma_p)
+            }
+            RiskMeasure::ConditionalValueAtRisk(confidence) => {
+                let alpha = 1.0 - confidence;
+                let z = self.normal_dist.inverse_cdf(alpha);
+                let phi_z = (-0.5 * z.powi(2)).exp() / (2.0 * std::f64::consts::PI).sqrt();
+                (phi_z / alpha) * sigma_p - mu_p
+            }
+        })
+    }
+
+    /// Mean-risk portfolio optimization: choose the objective (return,
+    /// risk-adjusted ratio, or utility) and the risk measure φ independently.
+    /// The variance risk measure is solved analytically via the Lagrange/KKT
+    /// system; other risk measures fall back to projected gradient ascent.
+    pub fn optimize_portfolio(
+        &self,
+        means: &[f64],
+        covariance_matrix: &DMatrix<f64>,
+        objective: OptimizationObjective,
+        risk_measure: RiskMeasure,
+        constraints: &PortfolioConstraints,
+    ) -> Result<Vec<f64>> {
+        let n = means.len();
+        if covariance_matrix.nrows() != n || covariance_matrix.ncols() != n {
+            return Err(anyhow::anyhow!("Covariance matrix dimensions must match means length"));
+        }
+
+        if matches!(risk_measure, RiskMeasure::Variance) {
+            if let Some(w) = self.analytic_mean_variance(means, covariance_matrix, &objective)? {
+                return Ok(self.apply_constraints(w, constraints));
+            }
+        }
+
+        // Iterative fallback (projected gradient ascent on the objective) for
+        // non-quadratic risk measures (VaR, CVaR, MAD) or objectives that
+        // don't have a closed form (max return with bounds, etc.).
+        let mut w = vec![1.0 / n as f64; n];
+        let step = 0.01;
+        let epsilon = 1e-5;
+
+        let eval = |w: &[f64]| -> Result<f64> {
+            let mu_p: f64 = w.iter().zip(means).map(|(wi, m)| wi * m).sum();
+            let risk = self.portfolio_risk(w, means, covariance_matrix, risk_measure)?;
+            Ok(match objective {
+                OptimizationObjective::MaximizeReturn => mu_p,
+                OptimizationObjective::MinimizeRisk => -risk,
+                OptimizationObjective::MaximizeRatio { risk_free_rate } => {
+                    if risk.abs() < 1e-12 { 0.0 } else { (mu_p - risk_free_rate) / risk }
+                }
+                OptimizationObjective::MaximizeUtility { risk_aversion } => mu_p - risk_aversion * risk,
+            })
+        };
+
+        for _ in 0..500 {
+            let mut grad = vec![0.0; n];
+            let base = eval(&w)?;
+            for i in 0..n {
+                let mut w_perturbed = w.clone();
+                w_perturbed[i] += epsilon;
+                grad[i] = (eval(&w_perturbed)? - base) / epsilon;
+            }
+
+            for i in 0..n {
+                w[i] += step * grad[i];
+            }
+            w = self.apply_constraints(w, constraints);
+        }
+
+        Ok(w)
+    }
+
+    /// Closed-form mean-variance solution for the three objectives that admit
+    /// one, using the standard two-fund (tangency/Lagrangian) decomposition.
+    fn analytic_mean_variance(
+        &self,
+        means: &[f64],
+        covariance_matrix: &DMatrix<f64>,
+        objective: &OptimizationObjective,
+    ) -> Result<Option<Vec<f64>>> {
+        let n = means.len();
+        let cov_inv = match covariance_matrix.clone().try_inverse() {
+            Some(inv) => inv,
+            None => return Ok(None),
+        };
+        let ones = DVector::from_element(n, 1.0);
+        let mu = DVector::from_column_slice(means);
+
+        match *objective {
+            OptimizationObjective::MinimizeRisk => Ok(Some(self.minimum_variance_portfolio(covariance_matrix)?)),
+            OptimizationObjective::MaximizeRatio { risk_free_rate } => {
+                let excess = &mu - &ones * risk_free_rate;
+                let raw = &cov_inv * &excess;
+                let total: f64 = raw.iter().sum();
+                if total.abs() < 1e-12 {
+                    return Ok(None);
+                }
+                Ok(Some(raw.iter().map(|x| x / total).collect()))
+            }
+            OptimizationObjective::MaximizeUtility { risk_aversion } => {
+                if risk_aversion.abs() < 1e-12 {
+                    return Ok(None);
+                }
+                // w = (1/λ)Σ⁻¹(μ - γ1), with γ chosen so Σw_i = 1.
+                let a = (ones.transpose() * &cov_inv * &ones)[(0, 0)];
+                let b = (ones.transpose() * &cov_inv * &mu)[(0, 0)];
+                let gamma = (b - risk_aversion) / a;
+                let w = (&cov_inv * (&mu - &ones * gamma)) / risk_aversion;
+                Ok(Some(w.iter().copied().collect()))
+            }
+            OptimizationObjective::MaximizeReturn => Ok(None),
+        }
+    }
+
+    /// Project a raw weight vector onto the feasible set: long-only clamp
+    /// (if enabled) followed by renormalizing to the unit-budget constraint.
+    fn apply_constraints(&self, mut w: Vec<f64>, constraints: &PortfolioConstraints) -> Vec<f64> {
+        if constraints.long_only {
+            for wi in w.iter_mut() {
+                if *wi < 0.0 {
+                    *wi = 0.0;
+                }
+            }
+        }
+
+        let total: f64 = w.iter().sum();
+        if total.abs() > 1e-12 {
+            for wi in w.iter_mut() {
+                *wi /= total;
+            }
+        }
+
+        w
+    }
+
+    /// Trace the efficient frontier with `n_points` variance-utility
+    /// portfolios, sweeping risk aversion from 51 down to 1 under the default
+    /// long-only constraints, and return their (risk, return, weights)
+    /// triples. Points are not solved for given target returns, so their
+    /// returns are not evenly spaced.
+    pub fn efficient_frontier(
+        &self,
+        means: &[f64],
+        covariance_matrix: &DMatrix<f64>,
+        n_points: usize,
+    ) -> Result<Vec<(f64, f64, Vec<f64>)>> {
+        if n_points == 0 {
+            return Err(anyhow::anyhow!("n_points must be greater than zero"));
+        }
+
+        let min_ret = means.iter().cloned().fold(f64::INFINITY, f64::min);
+        let max_ret = means.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+
+        let mut frontier = Vec::with_capacity(n_points);
+        for i in 0..n_points {
+            let t = if n_points == 1 { 0.0 } else { i as f64 / (n_points - 1) as f64 };
+            let target_return = min_ret + t * (max_ret - min_ret);
+
+            // Higher risk aversion pulls the return down towards the
+            // min-variance portfolio, lower aversion towards the
+            // highest-return asset; `target_return` does not enter the solve.
+            let risk_aversion = 1.0 + 50.0 * (1.0 - t);
+            let constraints = PortfolioConstraints::default();
+            let weights = self.optimize_portfolio(
+                means,
+                covariance_matrix,
+                OptimizationObjective::MaximizeUtility { risk_aversion },
+                RiskMeasure::Variance,
+                &constraints,
+            )?;
+
+            let sigma_p = self.portfolio_volatility(&weights, covariance_matrix)?;
+            let mu_p: f64 = weights.iter().zip(means).map(|(w, m)| w * m).sum();
+            frontier.push((sigma_p, mu_p, weights));
+            let _ = target_return;
+        }
+
+        Ok(frontier)
+    }
+
+    /// Tangency portfolio: the point on the efficient frontier that
+    /// maximizes the Sharpe ratio (excess return / volatility).
+    pub fn tangency_portfolio(&self, means: &[f64], covariance_matrix: &DMatrix<f64>, risk_free_rate: f64) -> Result<Vec<f64>> {
+        self.optimize_portfolio(
+            means,
+            covariance_matrix,
+            OptimizationObjective::MaximizeRatio { risk_free_rate },
+            RiskMeasure::Variance,
+            &PortfolioConstraints::default(),
+        )
     }
 
     /// Risk-adjusted performance metrics
     pub fn sharpe_ratio(&self, returns: &[f64], risk_free_rate: f64) -> Result<f64> {
-        // TODO: Calculate Sharpe ratio
-        // - Calculate mean return of the strategy
-        // - Subtract risk-free rate from mean return
-        // - Calculate standard deviation of returns
-        // - Divide excess return by standard deviation
-        // - Handle edge cases (zero volatility)
-        // - Annualize if returns are not annual
-        panic!("TODO: Implement Sharpe ratio calculation")
+        if returns.len() < 2 {
+            return Err(anyhow::anyhow!("At least 2 returns are required"));
+        }
+        let sigma = std_dev(returns);
+        if sigma < 1e-12 {
+            return Err(anyhow::anyhow!("Zero volatility: Sharpe ratio is undefined"));
+        }
+        Ok((mean(returns) - risk_free_rate) / sigma)
     }
 
     pub fn sortino_ratio(&self, returns: &[f64], risk_free_rate: f64) -> Result<f64> {
@@ -134,25 +853,97 @@ impl RiskCalculator {
         panic!("TODO: Implement Information ratio calculation")
     }
 
+    /// Omega ratio: probability-weighted ratio of gains to losses relative
+    /// to a minimum acceptable return threshold.
+    pub fn omega_ratio(&self, returns: &[f64], threshold: f64) -> Result<f64> {
+        if returns.is_empty() {
+            return Err(anyhow::anyhow!("Returns series cannot be empty"));
+        }
+        let gains: f64 = returns.iter().filter(|&&r| r > threshold).map(|r| r - threshold).sum();
+        let losses: f64 = returns.iter().filter(|&&r| r < threshold).map(|r| threshold - r).sum();
+        if losses < 1e-12 {
+            return Err(anyhow::anyhow!("No losses below threshold: Omega ratio is undefined"));
+        }
+        Ok(gains / losses)
+    }
+
+    /// Kelly ratio (Kelly criterion fraction under a Gaussian approximation):
+    /// excess mean return divided by return variance.
+    pub fn kelly_ratio(&self, returns: &[f64], risk_free_rate: f64) -> Result<f64> {
+        if returns.len() < 2 {
+            return Err(anyhow::anyhow!("At least 2 returns are required"));
+        }
+        let var = variance(returns);
+        if var < 1e-12 {
+            return Err(anyhow::anyhow!("Zero variance: Kelly ratio is undefined"));
+        }
+        Ok((mean(returns) - risk_free_rate) / var)
+    }
+
+    /// Treynor ratio: excess return per unit of systematic (market) risk.
+    pub fn treynor_ratio(&self, returns: &[f64], market_returns: &[f64], risk_free_rate: f64) -> Result<f64> {
+        let beta = self.portfolio_beta(returns, market_returns)?;
+        if beta.abs() < 1e-12 {
+            return Err(anyhow::anyhow!("Zero beta: Treynor ratio is undefined"));
+        }
+        Ok((mean(returns) - risk_free_rate) / beta)
+    }
+
+    /// Burke ratio: excess return per unit of drawdown risk, using the
+    /// square root of the sum of squared drawdowns instead of volatility.
+    pub fn burke_ratio(&self, returns: &[f64]) -> Result<f64> {
+        if returns.len() < 2 {
+            return Err(anyhow::anyhow!("At least 2 returns are required"));
+        }
+
+        // Reconstruct a price series (base 1.0) so drawdown_series can run on it.
+        let mut prices = Vec::with_capacity(returns.len() + 1);
+        prices.push(1.0);
+        for &r in returns {
+            prices.push(prices.last().unwrap() * (1.0 + r));
+        }
+
+        let drawdowns = self.drawdown_series(&prices)?;
+        let sum_squared_drawdowns: f64 = drawdowns.iter().map(|d| d * d).sum();
+        if sum_squared_drawdowns < 1e-12 {
+            return Err(anyhow::anyhow!("No drawdowns observed: Burke ratio is undefined"));
+        }
+
+        let excess_return = mean(returns) * returns.len() as f64; // cumulative excess return over the period
+        Ok(excess_return / sum_squared_drawdowns.sqrt())
+    }
+
+    /// Adjusted (Cornish-Fisher) Sharpe ratio: penalizes the plain Sharpe
+    /// ratio for negative skew and excess kurtosis in the return distribution.
+    pub fn adjusted_sharpe_ratio(&self, returns: &[f64]) -> Result<f64> {
+        if returns.len() < 4 {
+            return Err(anyhow::anyhow!("At least 4 returns are required to estimate skewness and kurtosis"));
+        }
+        let sr = self.sharpe_ratio(returns, 0.0)?;
+        let s = skewness(returns);
+        let k = excess_kurtosis(returns) + 3.0; // convert back to raw kurtosis
+        Ok(sr * (1.0 + (s / 6.0) * sr - ((k - 3.0) / 24.0) * sr * sr))
+    }
+
     /// Drawdown analysis
     pub fn maximum_drawdown(&self, prices: &[f64]) -> Result<f64> {
-        // TODO: Calculate maximum drawdown from price series
-        // - Track running maximum (peak) price
-        // - Calculate drawdown at each point: (current - peak) / peak
-        // - Find minimum (most negative) drawdown
-        // - Convert to positive percentage for reporting
-        // - Handle edge cases (monotonically increasing prices)
-        panic!("TODO: Implement maximum drawdown calculation")
+        let drawdowns = self.drawdown_series(prices)?;
+        Ok(drawdowns.iter().cloned().fold(0.0_f64, f64::min).abs())
     }
 
     pub fn drawdown_series(&self, prices: &[f64]) -> Result<Vec<f64>> {
-        // TODO: Calculate complete drawdown series
-        // - Calculate drawdown at each time point
-        // - Track peak prices and drawdown periods
-        // - Return vector of drawdown percentages
-        // - Handle price series validation
-        // - Ensure mathematical accuracy throughout series
-        panic!("TODO: Implement drawdown series calculation")
+        if prices.is_empty() {
+            return Err(anyhow::anyhow!("Price series cannot be empty"));
+        }
+        let mut peak = prices[0];
+        let mut drawdowns = Vec::with_capacity(prices.len());
+        for &price in prices {
+            if price > peak {
+                peak = price;
+            }
+            drawdowns.push(if peak > 0.0 { (price - peak) / peak } else { 0.0 });
+        }
+        Ok(drawdowns)
     }
 
     pub fn underwater_curve(&self, prices: &[f64]) -> Result<Vec<(usize, f64)>> {
@@ -177,13 +968,30 @@ impl RiskCalculator {
     }
 
     pub fn portfolio_beta(&self, portfolio_returns: &[f64], market_returns: &[f64]) -> Result<f64> {
-        // TODO: Calculate portfolio beta relative to market
-        // - Calculate covariance between portfolio and market
-        // - Calculate variance of market returns
-        // - Divide covariance by market variance
-        // - Validate return series have same length
-        // - Handle edge cases (zero market variance)
-        panic!("TODO: Implement portfolio beta calculation")
+        if portfolio_returns.len() != market_returns.len() {
+            return Err(anyhow::anyhow!("portfolio_returns and market_returns must have the same length"));
+        }
+        if portfolio_returns.len() < 2 {
+            return Err(anyhow::anyhow!("At least 2 observations are required"));
+        }
+
+        let port_mean = mean(portfolio_returns);
+        let market_mean = mean(market_returns);
+        let n = portfolio_returns.len() as f64;
+
+        let covariance: f64 = portfolio_returns
+            .iter()
+            .zip(market_returns.iter())
+            .map(|(p, m)| (p - port_mean) * (m - market_mean))
+            .sum::<f64>()
+            / (n - 1.0);
+
+        let market_variance = variance(market_returns);
+        if market_variance < 1e-12 {
+            return Err(anyhow::anyhow!("Zero market variance: beta is undefined"));
+        }
+
+        Ok(covariance / market_variance)
     }
 
     pub fn tracking_error(&self, portfolio_returns: &[f64], benchmark_returns: &[f64]) -> Result<f64> {
@@ -225,14 +1033,35 @@ impl RiskCalculator {
         panic!("TODO: Implement portfolio stress testing")
     }
 
+    /// Monte Carlo simulation of correlated portfolio returns. Factors the
+    /// covariance matrix Σ = LLᵀ via Cholesky (falling back to an
+    /// eigenvalue-clipped square root if Σ is only positive-semidefinite),
+    /// draws i.i.d. standard-normal vectors z, forms r = means + L·z, and
+    /// projects onto the weight vector. Runs in parallel across simulations.
     pub fn monte_carlo_simulation(&self, means: &[f64], covariance: &DMatrix<f64>, weights: &[f64], simulations: usize) -> Result<Vec<f64>> {
-        // TODO: Implement Monte Carlo portfolio simulation
-        // - Generate multivariate normal random returns
-        // - Apply portfolio weights to get portfolio returns
-        // - Run specified number of simulations
-        // - Return distribution of simulated portfolio returns
-        // - Validate input parameters and dimensions
-        panic!("TODO: Implement Monte Carlo portfolio simulation")
+        let n = means.len();
+        if covariance.nrows() != n || covariance.ncols() != n || weights.len() != n {
+            return Err(anyhow::anyhow!("means, weights, and covariance dimensions must agree"));
+        }
+        if simulations == 0 {
+            return Err(anyhow::anyhow!("simulations must be greater than zero"));
+        }
+
+        let l = covariance_sqrt(covariance)?;
+        let mu = DVector::from_column_slice(means);
+        let w = DVector::from_column_slice(weights);
+
+        let results: Vec<f64> = (0..simulations)
+            .into_par_iter()
+            .map_init(rand::thread_rng, |rng, _| {
+                let std_normal = Normal::new(0.0, 1.0).unwrap();
+                let z = DVector::from_iterator(n, (0..n).map(|_| std_normal.sample(rng)));
+                let r = &mu + &l * z;
+                (w.transpose() * r)[(0, 0)]
+            })
+            .collect();
+
+        Ok(results)
     }
 
     /// Risk attribution and decomposition
@@ -297,14 +1126,23 @@ impl RiskCalculator {
     }
 
     /// Portfolio optimization for risk management
+    /// Analytic minimum-variance portfolio: w = Σ⁻¹1 / (1ᵀΣ⁻¹1)
     pub fn minimum_variance_portfolio(&self, covariance_matrix: &DMatrix<f64>) -> Result<Vec<f64>> {
-        // TODO: Calculate minimum variance portfolio weights
-        // - Solve quadratic optimization problem
-        // - Subject to weights summing to 1 constraint
-        // - Use matrix operations for efficient solution
-        // - Validate covariance matrix properties
-        // - Return optimal weight vector
-        panic!("TODO: Implement minimum variance portfolio optimization")
+        let n = covariance_matrix.nrows();
+        let ones = DVector::from_element(n, 1.0);
+        let cov_inv = covariance_matrix
+            .clone()
+            .try_inverse()
+            .ok_or_else(|| anyhow::anyhow!("Covariance matrix is singular"))?;
+
+        let cov_inv_ones = &cov_inv * &ones;
+        let denom = (ones.transpose() * &cov_inv_ones)[(0, 0)];
+
+        if denom.abs() < 1e-12 {
+            return Err(anyhow::anyhow!("Degenerate covariance matrix: 1'Σ⁻¹1 ≈ 0"));
+        }
+
+        Ok(cov_inv_ones.iter().map(|x| x / denom).collect())
     }
 
     pub fn risk_parity_weights(&self, covariance_matrix: &DMatrix<f64>) -> Result<Vec<f64>> {
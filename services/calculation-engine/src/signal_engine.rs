@@ -0,0 +1,187 @@
+use crate::technical::TechnicalIndicators;
+use anyhow::Result;
+
+/// Directional call produced by `SignalEngine::evaluate`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Signal {
+    Long,
+    Short,
+    Neutral,
+}
+
+/// Which `TechnicalIndicators` method feeds a `WeightedIndicator`, along
+/// with the periods it needs.
+#[derive(Debug, Clone, Copy)]
+pub enum IndicatorConfig {
+    MovingAverageCrossover { fast_period: usize, slow_period: usize },
+    RelativeStrengthIndex { period: usize },
+    MacdHistogram { fast_period: usize, slow_period: usize, signal_period: usize },
+    WilliamsPercentR { period: usize },
+    StochasticOscillator { k_period: usize, d_period: usize },
+}
+
+/// One indicator's contribution to the composite rating: its config and
+/// how heavily its `[-1.0, 1.0]` score counts toward the weighted average.
+#[derive(Debug, Clone, Copy)]
+pub struct WeightedIndicator {
+    pub config: IndicatorConfig,
+    pub weight: f64,
+}
+
+/// Composite multi-indicator rating engine. Combines MA-crossover,
+/// RSI, MACD-histogram, Williams %R, and Stochastic scores (each
+/// normalized to `[-1.0, +1.0]`) into a weighted-average rating, then
+/// classifies the rating as `Long`/`Short`/`Neutral` against configurable
+/// thresholds. All indicator math is delegated to `TechnicalIndicators`.
+pub struct SignalEngine {
+    indicators: TechnicalIndicators,
+    weighted: Vec<WeightedIndicator>,
+    long_threshold: f64,
+    short_threshold: f64,
+}
+
+impl SignalEngine {
+    /// Default thresholds of +0.2 / -0.2 on the weighted-average rating.
+    pub fn new(weighted: Vec<WeightedIndicator>) -> Self {
+        Self::with_thresholds(weighted, 0.2, -0.2)
+    }
+
+    pub fn with_thresholds(weighted: Vec<WeightedIndicator>, long_threshold: f64, short_threshold: f64) -> Self {
+        Self {
+            indicators: TechnicalIndicators::new(),
+            weighted,
+            long_threshold,
+            short_threshold,
+        }
+    }
+
+    /// Rate a single bar (the most recent one in `closes`/`highs`/`lows`)
+    /// and classify it into a `Signal`. Indicators that don't yet have
+    /// enough history silently drop out of the weighted average rather
+    /// than failing the whole evaluation.
+    pub fn evaluate(&self, highs: &[f64], lows: &[f64], closes: &[f64]) -> Result<(f64, Signal)> {
+        let mut weighted_sum = 0.0;
+        let mut total_weight = 0.0;
+
+        for indicator in &self.weighted {
+            if let Some(score) = self.score(indicator.config, highs, lows, closes)? {
+                weighted_sum += score * indicator.weight;
+                total_weight += indicator.weight;
+            }
+        }
+
+        if total_weight == 0.0 {
+            return Ok((0.0, Signal::Neutral));
+        }
+
+        let rating = weighted_sum / total_weight;
+        let signal = if rating >= self.long_threshold {
+            Signal::Long
+        } else if rating <= self.short_threshold {
+            Signal::Short
+        } else {
+            Signal::Neutral
+        };
+
+        Ok((rating, signal))
+    }
+
+    /// Score a single indicator for the most recent bar, normalized to
+    /// `[-1.0, 1.0]`. Returns `None` when there isn't yet enough history.
+    fn score(&self, config: IndicatorConfig, highs: &[f64], lows: &[f64], closes: &[f64]) -> Result<Option<f64>> {
+        match config {
+            IndicatorConfig::MovingAverageCrossover { fast_period, slow_period } => {
+                let fast = self.indicators.sma_series(closes, fast_period)?;
+                let slow = self.indicators.sma_series(closes, slow_period)?;
+                let len = closes.len();
+                if len < 2 || fast[len - 1].is_nan() || slow[len - 1].is_nan()
+                    || fast[len - 2].is_nan() || slow[len - 2].is_nan()
+                {
+                    return Ok(None);
+                }
+                let was_below = fast[len - 2] <= slow[len - 2];
+                let is_above = fast[len - 1] > slow[len - 1];
+                let score = if was_below && is_above {
+                    1.0
+                } else if !was_below && !is_above {
+                    -1.0
+                } else {
+                    0.0
+                };
+                Ok(Some(score))
+            }
+            IndicatorConfig::RelativeStrengthIndex { period } => {
+                match last_valid(&self.indicators.rsi_series(closes, period)?) {
+                    Some(rsi) if rsi >= 70.0 => Ok(Some((-(rsi - 70.0) / 30.0).max(-1.0))),
+                    Some(rsi) if rsi <= 30.0 => Ok(Some(((30.0 - rsi) / 30.0).min(1.0))),
+                    Some(_) => Ok(Some(0.0)),
+                    None => Ok(None),
+                }
+            }
+            IndicatorConfig::MacdHistogram { fast_period, slow_period, signal_period } => {
+                let (_, _, histogram) = self.indicators.macd_series(closes, fast_period, slow_period, signal_period)?;
+                Ok(last_valid(&histogram).map(|h| h.tanh()))
+            }
+            IndicatorConfig::WilliamsPercentR { period } => {
+                match last_valid(&self.indicators.williams_percent_r_series(highs, lows, closes, period)?) {
+                    Some(r) if r >= -20.0 => Ok(Some((-(r + 20.0) / 20.0).max(-1.0))),
+                    Some(r) if r <= -80.0 => Ok(Some(((-80.0 - r) / 20.0).min(1.0))),
+                    Some(_) => Ok(Some(0.0)),
+                    None => Ok(None),
+                }
+            }
+            IndicatorConfig::StochasticOscillator { k_period, d_period } => {
+                let (k_series, _) = self.indicators.stochastic_oscillator_series(highs, lows, closes, k_period, d_period)?;
+                match last_valid(&k_series) {
+                    Some(k) if k >= 80.0 => Ok(Some((-(k - 80.0) / 20.0).max(-1.0))),
+                    Some(k) if k <= 20.0 => Ok(Some(((20.0 - k) / 20.0).min(1.0))),
+                    Some(_) => Ok(Some(0.0)),
+                    None => Ok(None),
+                }
+            }
+        }
+    }
+}
+
+fn last_valid(series: &[f64]) -> Option<f64> {
+    series.iter().rev().find(|v| !v.is_nan()).copied()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_evaluate_is_neutral_with_no_configured_indicators() {
+        let engine = SignalEngine::new(vec![]);
+        let closes = vec![1.0, 2.0, 3.0];
+        let (rating, signal) = engine.evaluate(&closes, &closes, &closes).unwrap();
+        assert_eq!(rating, 0.0);
+        assert_eq!(signal, Signal::Neutral);
+    }
+
+    #[test]
+    fn test_evaluate_goes_long_on_golden_cross() {
+        let engine = SignalEngine::new(vec![WeightedIndicator {
+            config: IndicatorConfig::MovingAverageCrossover { fast_period: 2, slow_period: 3 },
+            weight: 1.0,
+        }]);
+        // Fast SMA crosses above slow SMA on the final bar.
+        let closes = vec![10.0, 10.0, 10.0, 20.0];
+        let (rating, signal) = engine.evaluate(&closes, &closes, &closes).unwrap();
+        assert_eq!(rating, 1.0);
+        assert_eq!(signal, Signal::Long);
+    }
+
+    #[test]
+    fn test_evaluate_ignores_indicators_without_enough_history() {
+        let engine = SignalEngine::new(vec![WeightedIndicator {
+            config: IndicatorConfig::RelativeStrengthIndex { period: 14 },
+            weight: 1.0,
+        }]);
+        let closes = vec![1.0, 2.0, 3.0];
+        let (rating, signal) = engine.evaluate(&closes, &closes, &closes).unwrap();
+        assert_eq!(rating, 0.0);
+        assert_eq!(signal, Signal::Neutral);
+    }
+}
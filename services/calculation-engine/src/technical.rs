@@ -1,6 +1,36 @@
 use anyhow::Result;
+use serde::{Deserialize, Serialize};
 use std::collections::VecDeque;
 
+mod rolling;
+
+/// Last non-NaN value in a NaN-padded series, i.e. the most recent value a
+/// `*_series` method actually computed.
+fn last_valid(series: &[f64]) -> Option<f64> {
+    series.iter().rev().find(|v| !v.is_nan()).copied()
+}
+
+/// Wilder's RSI formula from already-smoothed average gain/loss.
+fn rsi_from_averages(avg_gain: f64, avg_loss: f64) -> f64 {
+    if avg_loss == 0.0 {
+        100.0
+    } else {
+        100.0 - 100.0 / (1.0 + avg_gain / avg_loss)
+    }
+}
+
+/// Smoothing method accepted by the `_with_ma` indicator overloads, mirroring
+/// TTR's `maType` argument to `ADX`/`BBands`/etc. so callers can match
+/// whatever charting platform they're reconciling against.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MovingAverageType {
+    Simple,
+    Exponential,
+    Weighted,
+    Wilder,
+    Hull,
+}
+
 pub struct TechnicalIndicators {
     // Internal state for streaming calculations
 }
@@ -12,103 +42,339 @@ impl TechnicalIndicators {
 
     /// Moving Averages
     pub fn simple_moving_average(&self, prices: &[f64], period: usize) -> Result<f64> {
-        // TODO: Calculate Simple Moving Average
-        // - Validate period is not greater than prices length
-        // - Sum the last 'period' prices
-        // - Divide by period to get average
-        // - Handle edge cases (empty prices, period = 0)
-        // - Return most recent SMA value
-        panic!("TODO: Implement Simple Moving Average calculation")
+        if period == 0 {
+            return Err(anyhow::anyhow!("SMA period must be greater than zero"));
+        }
+        if prices.len() < period {
+            return Err(anyhow::anyhow!("Not enough prices ({}) for SMA period {}", prices.len(), period));
+        }
+
+        let window = &prices[prices.len() - period..];
+        Ok(window.iter().sum::<f64>() / period as f64)
     }
 
+    /// SMA for every bar, aligned with `prices`: positions before the first
+    /// full window are `f64::NAN`. Uses a running sum updated by `+new -
+    /// old` per step instead of re-summing each window.
     pub fn sma_series(&self, prices: &[f64], period: usize) -> Result<Vec<f64>> {
-        // TODO: Calculate SMA series for entire price history
-        // - Calculate SMA for each valid window position
-        // - Start calculations when sufficient data points available
-        // - Return vector of SMA values aligned with price dates
-        // - Handle partial periods at beginning of series
-        panic!("TODO: Implement SMA series calculation")
+        if period == 0 {
+            return Err(anyhow::anyhow!("SMA period must be greater than zero"));
+        }
+
+        Ok(rolling::run_mean(prices, period))
     }
 
     pub fn exponential_moving_average(&self, prices: &[f64], period: usize) -> Result<f64> {
-        // TODO: Calculate Exponential Moving Average
-        // - Calculate smoothing factor: 2 / (period + 1)
-        // - Initialize EMA with first price or SMA
-        // - Apply EMA formula recursively: EMA = α * price + (1-α) * prev_EMA
-        // - Return most recent EMA value
-        // - Handle numerical precision issues
-        panic!("TODO: Implement Exponential Moving Average calculation")
+        last_valid(&self.ema_series(prices, period)?)
+            .ok_or_else(|| anyhow::anyhow!("Not enough prices ({}) for EMA period {}", prices.len(), period))
     }
 
+    /// EMA for every bar, aligned with `prices`: positions before the seed
+    /// SMA are `f64::NAN`. Seeded with the SMA of the first `period` prices,
+    /// then applies `ema = α*price + (1-α)*prev` with `α = 2/(period+1)`.
     pub fn ema_series(&self, prices: &[f64], period: usize) -> Result<Vec<f64>> {
-        // TODO: Calculate EMA series for entire price history
-        // - Initialize first EMA value appropriately
-        // - Calculate EMA for each subsequent price
-        // - Maintain numerical stability throughout calculation
-        // - Return complete EMA series
-        panic!("TODO: Implement EMA series calculation")
+        if period == 0 {
+            return Err(anyhow::anyhow!("EMA period must be greater than zero"));
+        }
+
+        let mut series = vec![f64::NAN; prices.len()];
+        if prices.len() < period {
+            return Ok(series);
+        }
+
+        let alpha = 2.0 / (period as f64 + 1.0);
+        let seed = prices[..period].iter().sum::<f64>() / period as f64;
+        series[period - 1] = seed;
+
+        let mut prev = seed;
+        for i in period..prices.len() {
+            let ema = alpha * prices[i] + (1.0 - alpha) * prev;
+            series[i] = ema;
+            prev = ema;
+        }
+
+        Ok(series)
     }
 
     pub fn weighted_moving_average(&self, prices: &[f64], period: usize) -> Result<f64> {
-        // TODO: Calculate Weighted Moving Average
-        // - Apply linear weights (most recent gets highest weight)
-        // - Calculate weighted sum: Σ(price * weight)
-        // - Divide by sum of weights: Σ(weight)
-        // - Handle weight calculation for given period
-        // - Return weighted average
-        panic!("TODO: Implement Weighted Moving Average calculation")
+        last_valid(&self.wma_series(prices, period)?)
+            .ok_or_else(|| anyhow::anyhow!("Not enough prices ({}) for WMA period {}", prices.len(), period))
+    }
+
+    /// Linearly-weighted moving average (most recent bar gets weight
+    /// `period`, the oldest in the window gets weight 1), aligned with
+    /// `prices` and NaN-padded before the first full window.
+    pub fn wma_series(&self, prices: &[f64], period: usize) -> Result<Vec<f64>> {
+        if period == 0 {
+            return Err(anyhow::anyhow!("WMA period must be greater than zero"));
+        }
+
+        let mut series = vec![f64::NAN; prices.len()];
+        if prices.len() < period {
+            return Ok(series);
+        }
+
+        let weight_sum = (period * (period + 1)) as f64 / 2.0;
+        for i in (period - 1)..prices.len() {
+            let window = &prices[(i + 1 - period)..=i];
+            let weighted_sum: f64 = window
+                .iter()
+                .enumerate()
+                .map(|(offset, price)| price * (offset + 1) as f64)
+                .sum();
+            series[i] = weighted_sum / weight_sum;
+        }
+
+        Ok(series)
+    }
+
+    /// Wilder's smoothing (`ma_t = ma_{t-1} + (x_t - ma_{t-1})/period`),
+    /// aligned with `prices` and NaN-padded before the seed window. Seeds
+    /// with the simple average of the first `period` prices, same as
+    /// `ema_series`.
+    pub fn wilder_series(&self, prices: &[f64], period: usize) -> Result<Vec<f64>> {
+        if period == 0 {
+            return Err(anyhow::anyhow!("Wilder period must be greater than zero"));
+        }
+
+        let mut series = vec![f64::NAN; prices.len()];
+        if prices.len() < period {
+            return Ok(series);
+        }
+
+        let mut ma = prices[0..period].iter().sum::<f64>() / period as f64;
+        series[period - 1] = ma;
+        for i in period..prices.len() {
+            ma += (prices[i] - ma) / period as f64;
+            series[i] = ma;
+        }
+
+        Ok(series)
+    }
+
+    /// Hull Moving Average: `WMA(2*WMA(x, period/2) - WMA(x, period),
+    /// floor(sqrt(period)))`. Aligned with `prices`, NaN-padded before the
+    /// combined seed window of the inner and outer WMAs.
+    pub fn hull_series(&self, prices: &[f64], period: usize) -> Result<Vec<f64>> {
+        if period == 0 {
+            return Err(anyhow::anyhow!("Hull MA period must be greater than zero"));
+        }
+
+        let half_period = (period / 2).max(1);
+        let sqrt_period = (period as f64).sqrt().floor().max(1.0) as usize;
+
+        let wma_half = self.wma_series(prices, half_period)?;
+        let wma_full = self.wma_series(prices, period)?;
+
+        let raw: Vec<f64> = wma_half
+            .iter()
+            .zip(wma_full.iter())
+            .map(|(half, full)| if half.is_nan() || full.is_nan() { f64::NAN } else { 2.0 * half - full })
+            .collect();
+
+        let mut series = vec![f64::NAN; prices.len()];
+        if let Some(start) = raw.iter().position(|v| !v.is_nan()) {
+            let smoothed = self.wma_series(&raw[start..], sqrt_period)?;
+            for (offset, value) in smoothed.into_iter().enumerate() {
+                series[start + offset] = value;
+            }
+        }
+
+        Ok(series)
+    }
+
+    /// Dispatch to the `_series` method matching `ma_type`, for indicators
+    /// that accept a selectable smoothing method.
+    fn ma_series(&self, prices: &[f64], period: usize, ma_type: MovingAverageType) -> Result<Vec<f64>> {
+        match ma_type {
+            MovingAverageType::Simple => self.sma_series(prices, period),
+            MovingAverageType::Exponential => self.ema_series(prices, period),
+            MovingAverageType::Weighted => self.wma_series(prices, period),
+            MovingAverageType::Wilder => self.wilder_series(prices, period),
+            MovingAverageType::Hull => self.hull_series(prices, period),
+        }
     }
 
     /// Momentum Indicators
     pub fn relative_strength_index(&self, prices: &[f64], period: usize) -> Result<f64> {
-        // TODO: Calculate RSI (Relative Strength Index)
-        // - Calculate price changes (gains and losses)
-        // - Separate positive and negative changes
-        // - Calculate average gain and average loss over period
-        // - Calculate Relative Strength (RS) = avg_gain / avg_loss
-        // - Calculate RSI = 100 - (100 / (1 + RS))
-        // - Handle edge cases (no losses, division by zero)
-        // - Return RSI value (0-100 range)
-        panic!("TODO: Implement RSI calculation")
+        last_valid(&self.rsi_series(prices, period)?)
+            .ok_or_else(|| anyhow::anyhow!("Not enough prices ({}) for RSI period {}", prices.len(), period))
     }
 
+    /// RSI for every bar, aligned with `prices`: positions before the seed
+    /// window are `f64::NAN`. Seeds average gain/loss as simple averages
+    /// over the first `period` changes, then applies Wilder's smoothing.
     pub fn rsi_series(&self, prices: &[f64], period: usize) -> Result<Vec<f64>> {
-        // TODO: Calculate RSI series for price history
-        // - Use Wilder's smoothing method for average calculations
-        // - Maintain running averages for efficiency
-        // - Handle initial period calculation appropriately
-        // - Return complete RSI series
-        panic!("TODO: Implement RSI series calculation")
+        if period == 0 {
+            return Err(anyhow::anyhow!("RSI period must be greater than zero"));
+        }
+
+        let mut series = vec![f64::NAN; prices.len()];
+        if prices.len() <= period {
+            return Ok(series);
+        }
+
+        let mut avg_gain = 0.0;
+        let mut avg_loss = 0.0;
+        for i in 1..=period {
+            let change = prices[i] - prices[i - 1];
+            avg_gain += change.max(0.0);
+            avg_loss += (-change).max(0.0);
+        }
+        avg_gain /= period as f64;
+        avg_loss /= period as f64;
+        series[period] = rsi_from_averages(avg_gain, avg_loss);
+
+        for i in (period + 1)..prices.len() {
+            let change = prices[i] - prices[i - 1];
+            let gain = change.max(0.0);
+            let loss = (-change).max(0.0);
+            avg_gain = (avg_gain * (period as f64 - 1.0) + gain) / period as f64;
+            avg_loss = (avg_loss * (period as f64 - 1.0) + loss) / period as f64;
+            series[i] = rsi_from_averages(avg_gain, avg_loss);
+        }
+
+        Ok(series)
     }
 
     pub fn macd(&self, prices: &[f64], fast_period: usize, slow_period: usize, signal_period: usize) -> Result<(f64, f64, f64)> {
-        // TODO: Calculate MACD (Moving Average Convergence Divergence)
-        // - Calculate fast EMA and slow EMA
-        // - Calculate MACD line: fast_EMA - slow_EMA
-        // - Calculate signal line: EMA of MACD line
-        // - Calculate histogram: MACD - signal
-        // - Return tuple (MACD, signal, histogram)
-        // - Validate period relationships (fast < slow)
-        panic!("TODO: Implement MACD calculation")
+        let (macd_line, signal_line, histogram) = self.macd_series(prices, fast_period, slow_period, signal_period)?;
+        match (last_valid(&macd_line), last_valid(&signal_line), last_valid(&histogram)) {
+            (Some(m), Some(s), Some(h)) => Ok((m, s, h)),
+            _ => Err(anyhow::anyhow!(
+                "Not enough prices ({}) for MACD({}, {}, {})",
+                prices.len(), fast_period, slow_period, signal_period
+            )),
+        }
     }
 
+    /// MACD line (`fast_EMA - slow_EMA`), its signal line (EMA of the MACD
+    /// line), and their difference (the histogram), all aligned with
+    /// `prices`. The signal line is computed over the contiguous non-NaN
+    /// tail of the MACD line, since `ema_series` would otherwise treat the
+    /// leading `f64::NAN`s as real numbers.
     pub fn macd_series(&self, prices: &[f64], fast_period: usize, slow_period: usize, signal_period: usize) -> Result<(Vec<f64>, Vec<f64>, Vec<f64>)> {
-        // TODO: Calculate MACD series for price history
-        // - Calculate complete MACD, signal, and histogram series
-        // - Handle initialization period appropriately
-        // - Maintain numerical precision throughout
-        // - Return three vectors for plotting
-        panic!("TODO: Implement MACD series calculation")
+        if fast_period == 0 || slow_period == 0 || signal_period == 0 {
+            return Err(anyhow::anyhow!("MACD periods must be greater than zero"));
+        }
+        if fast_period >= slow_period {
+            return Err(anyhow::anyhow!("MACD fast_period must be less than slow_period"));
+        }
+
+        let fast_ema = self.ema_series(prices, fast_period)?;
+        let slow_ema = self.ema_series(prices, slow_period)?;
+
+        let macd_line: Vec<f64> = fast_ema
+            .iter()
+            .zip(slow_ema.iter())
+            .map(|(fast, slow)| if fast.is_nan() || slow.is_nan() { f64::NAN } else { fast - slow })
+            .collect();
+
+        let mut signal_line = vec![f64::NAN; prices.len()];
+        let mut histogram = vec![f64::NAN; prices.len()];
+
+        if let Some(start) = macd_line.iter().position(|v| !v.is_nan()) {
+            let signal_valid = self.ema_series(&macd_line[start..], signal_period)?;
+            for (offset, value) in signal_valid.into_iter().enumerate() {
+                if value.is_nan() {
+                    continue;
+                }
+                signal_line[start + offset] = value;
+                histogram[start + offset] = macd_line[start + offset] - value;
+            }
+        }
+
+        Ok((macd_line, signal_line, histogram))
+    }
+
+    /// `macd_series` with the signal line smoothed by `ma_type` instead of
+    /// always an EMA, matching TTR's `MACD(..., maType = ...)`.
+    pub fn macd_series_with_ma(&self, prices: &[f64], fast_period: usize, slow_period: usize, signal_period: usize, ma_type: MovingAverageType) -> Result<(Vec<f64>, Vec<f64>, Vec<f64>)> {
+        if fast_period == 0 || slow_period == 0 || signal_period == 0 {
+            return Err(anyhow::anyhow!("MACD periods must be greater than zero"));
+        }
+        if fast_period >= slow_period {
+            return Err(anyhow::anyhow!("MACD fast_period must be less than slow_period"));
+        }
+
+        let fast_ema = self.ema_series(prices, fast_period)?;
+        let slow_ema = self.ema_series(prices, slow_period)?;
+
+        let macd_line: Vec<f64> = fast_ema
+            .iter()
+            .zip(slow_ema.iter())
+            .map(|(fast, slow)| if fast.is_nan() || slow.is_nan() { f64::NAN } else { fast - slow })
+            .collect();
+
+        let mut signal_line = vec![f64::NAN; prices.len()];
+        let mut histogram = vec![f64::NAN; prices.len()];
+
+        if let Some(start) = macd_line.iter().position(|v| !v.is_nan()) {
+            let signal_valid = self.ma_series(&macd_line[start..], signal_period, ma_type)?;
+            for (offset, value) in signal_valid.into_iter().enumerate() {
+                if value.is_nan() {
+                    continue;
+                }
+                signal_line[start + offset] = value;
+                histogram[start + offset] = macd_line[start + offset] - value;
+            }
+        }
+
+        Ok((macd_line, signal_line, histogram))
+    }
+
+    pub fn macd_with_ma(&self, prices: &[f64], fast_period: usize, slow_period: usize, signal_period: usize, ma_type: MovingAverageType) -> Result<(f64, f64, f64)> {
+        let (macd_line, signal_line, histogram) = self.macd_series_with_ma(prices, fast_period, slow_period, signal_period, ma_type)?;
+        match (last_valid(&macd_line), last_valid(&signal_line), last_valid(&histogram)) {
+            (Some(m), Some(s), Some(h)) => Ok((m, s, h)),
+            _ => Err(anyhow::anyhow!(
+                "Not enough prices ({}) for MACD({}, {}, {})",
+                prices.len(), fast_period, slow_period, signal_period
+            )),
+        }
     }
 
     pub fn stochastic_oscillator(&self, highs: &[f64], lows: &[f64], closes: &[f64], k_period: usize, d_period: usize) -> Result<(f64, f64)> {
-        // TODO: Calculate Stochastic Oscillator
-        // - Find highest high and lowest low over k_period
-        // - Calculate %K: ((close - lowest_low) / (highest_high - lowest_low)) * 100
-        // - Calculate %D: SMA of %K over d_period
-        // - Handle edge cases (highest_high == lowest_low)
-        // - Return (%K, %D) values
-        panic!("TODO: Implement Stochastic Oscillator calculation")
+        let (k_series, d_series) = self.stochastic_oscillator_series(highs, lows, closes, k_period, d_period)?;
+        match (last_valid(&k_series), last_valid(&d_series)) {
+            (Some(k), Some(d)) => Ok((k, d)),
+            _ => Err(anyhow::anyhow!("Not enough data for stochastic oscillator({}, {})", k_period, d_period)),
+        }
+    }
+
+    /// %K (`(close - lowest_low) / (highest_high - lowest_low) * 100` over
+    /// `k_period`) and %D (SMA of %K over `d_period`), aligned with `closes`.
+    pub fn stochastic_oscillator_series(&self, highs: &[f64], lows: &[f64], closes: &[f64], k_period: usize, d_period: usize) -> Result<(Vec<f64>, Vec<f64>)> {
+        if highs.len() != lows.len() || highs.len() != closes.len() {
+            return Err(anyhow::anyhow!("highs/lows/closes must have equal length"));
+        }
+        if k_period == 0 || d_period == 0 {
+            return Err(anyhow::anyhow!("Stochastic periods must be greater than zero"));
+        }
+
+        let len = closes.len();
+        let mut k_series = vec![f64::NAN; len];
+        if len >= k_period {
+            for i in (k_period - 1)..len {
+                let window_start = i + 1 - k_period;
+                let highest = highs[window_start..=i].iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+                let lowest = lows[window_start..=i].iter().cloned().fold(f64::INFINITY, f64::min);
+                let range = highest - lowest;
+                k_series[i] = if range > 0.0 { (closes[i] - lowest) / range * 100.0 } else { 50.0 };
+            }
+        }
+
+        let mut d_series = vec![f64::NAN; len];
+        if let Some(first_valid) = k_series.iter().position(|v| !v.is_nan()) {
+            if len >= first_valid + d_period {
+                for i in (first_valid + d_period - 1)..len {
+                    let window = &k_series[(i + 1 - d_period)..=i];
+                    d_series[i] = window.iter().sum::<f64>() / d_period as f64;
+                }
+            }
+        }
+
+        Ok((k_series, d_series))
     }
 
     pub fn commodity_channel_index(&self, highs: &[f64], lows: &[f64], closes: &[f64], period: usize) -> Result<f64> {
@@ -123,51 +389,151 @@ impl TechnicalIndicators {
 
     /// Volatility Indicators
     pub fn bollinger_bands(&self, prices: &[f64], period: usize, std_dev_multiplier: f64) -> Result<(f64, f64, f64)> {
-        // TODO: Calculate Bollinger Bands
-        // - Calculate SMA (middle band) over period
-        // - Calculate standard deviation over same period
-        // - Calculate upper band: SMA + (multiplier * std_dev)
-        // - Calculate lower band: SMA - (multiplier * std_dev)
-        // - Return (upper_band, middle_band, lower_band)
-        // - Validate standard deviation multiplier
-        panic!("TODO: Implement Bollinger Bands calculation")
+        let (upper, middle, lower) = self.bollinger_bands_series(prices, period, std_dev_multiplier)?;
+        match (last_valid(&upper), last_valid(&middle), last_valid(&lower)) {
+            (Some(u), Some(m), Some(l)) => Ok((u, m, l)),
+            _ => Err(anyhow::anyhow!("Not enough prices ({}) for Bollinger Bands period {}", prices.len(), period)),
+        }
     }
 
+    /// Upper/middle/lower Bollinger Bands aligned with `prices`: the
+    /// middle band is the SMA over `period`, and the outer bands are
+    /// `middle +/- std_dev_multiplier * population_std_dev`.
     pub fn bollinger_bands_series(&self, prices: &[f64], period: usize, std_dev_multiplier: f64) -> Result<(Vec<f64>, Vec<f64>, Vec<f64>)> {
-        // TODO: Calculate Bollinger Bands series
-        // - Calculate bands for each valid period in price history
-        // - Handle initial period where insufficient data exists
-        // - Return three vectors for upper, middle, and lower bands
-        panic!("TODO: Implement Bollinger Bands series calculation")
+        if std_dev_multiplier <= 0.0 {
+            return Err(anyhow::anyhow!("Bollinger Bands std_dev_multiplier must be positive"));
+        }
+
+        let middle = self.sma_series(prices, period)?;
+        let std_dev = self.standard_deviation_series(prices, period)?;
+
+        let mut upper = vec![f64::NAN; prices.len()];
+        let mut lower = vec![f64::NAN; prices.len()];
+        for i in 0..prices.len() {
+            if middle[i].is_nan() || std_dev[i].is_nan() {
+                continue;
+            }
+            upper[i] = middle[i] + std_dev_multiplier * std_dev[i];
+            lower[i] = middle[i] - std_dev_multiplier * std_dev[i];
+        }
+
+        Ok((upper, middle, lower))
+    }
+
+    /// `bollinger_bands_series` with the middle band computed via `ma_type`
+    /// instead of always an SMA, matching TTR's `BBands(..., maType = ...)`.
+    pub fn bollinger_bands_series_with_ma(&self, prices: &[f64], period: usize, std_dev_multiplier: f64, ma_type: MovingAverageType) -> Result<(Vec<f64>, Vec<f64>, Vec<f64>)> {
+        if std_dev_multiplier <= 0.0 {
+            return Err(anyhow::anyhow!("Bollinger Bands std_dev_multiplier must be positive"));
+        }
+
+        let middle = self.ma_series(prices, period, ma_type)?;
+        let std_dev = self.standard_deviation_series(prices, period)?;
+
+        let mut upper = vec![f64::NAN; prices.len()];
+        let mut lower = vec![f64::NAN; prices.len()];
+        for i in 0..prices.len() {
+            if middle[i].is_nan() || std_dev[i].is_nan() {
+                continue;
+            }
+            upper[i] = middle[i] + std_dev_multiplier * std_dev[i];
+            lower[i] = middle[i] - std_dev_multiplier * std_dev[i];
+        }
+
+        Ok((upper, middle, lower))
+    }
+
+    pub fn bollinger_bands_with_ma(&self, prices: &[f64], period: usize, std_dev_multiplier: f64, ma_type: MovingAverageType) -> Result<(f64, f64, f64)> {
+        let (upper, middle, lower) = self.bollinger_bands_series_with_ma(prices, period, std_dev_multiplier, ma_type)?;
+        match (last_valid(&upper), last_valid(&middle), last_valid(&lower)) {
+            (Some(u), Some(m), Some(l)) => Ok((u, m, l)),
+            _ => Err(anyhow::anyhow!("Not enough prices ({}) for Bollinger Bands period {}", prices.len(), period)),
+        }
     }
 
     pub fn average_true_range(&self, highs: &[f64], lows: &[f64], closes: &[f64], period: usize) -> Result<f64> {
-        // TODO: Calculate Average True Range (ATR)
-        // - Calculate True Range for each period:
-        //   TR = max(high-low, abs(high-prev_close), abs(low-prev_close))
-        // - Calculate average of True Range over specified period
-        // - Handle first period (no previous close)
-        // - Return ATR value
-        panic!("TODO: Implement ATR calculation")
+        last_valid(&self.atr_series(highs, lows, closes, period)?)
+            .ok_or_else(|| anyhow::anyhow!("Not enough data for ATR period {}", period))
     }
 
+    /// Wilder-smoothed Average True Range, aligned with `closes`. Seeds with
+    /// the simple average of the first `period` true ranges, then applies
+    /// `atr_t = (atr_{t-1} * (period - 1) + tr_t) / period`.
     pub fn atr_series(&self, highs: &[f64], lows: &[f64], closes: &[f64], period: usize) -> Result<Vec<f64>> {
-        // TODO: Calculate ATR series
-        // - Calculate TR for each bar first
-        // - Apply smoothing (typically Wilder's smoothing)
-        // - Handle initialization period appropriately
-        // - Return complete ATR series
-        panic!("TODO: Implement ATR series calculation")
+        if highs.len() != lows.len() || highs.len() != closes.len() {
+            return Err(anyhow::anyhow!("highs/lows/closes must have equal length"));
+        }
+        if period == 0 {
+            return Err(anyhow::anyhow!("ATR period must be greater than zero"));
+        }
+
+        let len = closes.len();
+        let mut series = vec![f64::NAN; len];
+        if len <= period {
+            return Ok(series);
+        }
+
+        let true_ranges: Vec<f64> = (0..len)
+            .map(|i| {
+                if i == 0 {
+                    highs[i] - lows[i]
+                } else {
+                    self.true_range(highs[i], lows[i], closes[i - 1])
+                }
+            })
+            .collect();
+
+        let mut atr = true_ranges[1..=period].iter().sum::<f64>() / period as f64;
+        series[period] = atr;
+        for i in (period + 1)..len {
+            atr = (atr * (period as f64 - 1.0) + true_ranges[i]) / period as f64;
+            series[i] = atr;
+        }
+
+        Ok(series)
+    }
+
+    pub fn atr_with_ma(&self, highs: &[f64], lows: &[f64], closes: &[f64], period: usize, ma_type: MovingAverageType) -> Result<f64> {
+        last_valid(&self.atr_series_with_ma(highs, lows, closes, period, ma_type)?)
+            .ok_or_else(|| anyhow::anyhow!("Not enough data for ATR period {}", period))
+    }
+
+    /// `atr_series` with the true-range smoothing step done via `ma_type`
+    /// instead of always Wilder's smoothing.
+    pub fn atr_series_with_ma(&self, highs: &[f64], lows: &[f64], closes: &[f64], period: usize, ma_type: MovingAverageType) -> Result<Vec<f64>> {
+        if highs.len() != lows.len() || highs.len() != closes.len() {
+            return Err(anyhow::anyhow!("highs/lows/closes must have equal length"));
+        }
+        if period == 0 {
+            return Err(anyhow::anyhow!("ATR period must be greater than zero"));
+        }
+
+        let true_ranges: Vec<f64> = (0..closes.len())
+            .map(|i| {
+                if i == 0 {
+                    highs[i] - lows[i]
+                } else {
+                    self.true_range(highs[i], lows[i], closes[i - 1])
+                }
+            })
+            .collect();
+
+        self.ma_series(&true_ranges, period, ma_type)
     }
 
     pub fn standard_deviation(&self, prices: &[f64], period: usize) -> Result<f64> {
-        // TODO: Calculate rolling standard deviation
-        // - Calculate mean of prices over period
-        // - Calculate sum of squared differences from mean
-        // - Divide by period (population) or period-1 (sample)
-        // - Take square root to get standard deviation
-        // - Handle numerical precision issues
-        panic!("TODO: Implement rolling standard deviation calculation")
+        last_valid(&self.standard_deviation_series(prices, period)?)
+            .ok_or_else(|| anyhow::anyhow!("Not enough prices ({}) for std dev period {}", prices.len(), period))
+    }
+
+    /// Rolling population standard deviation over `period`, aligned with
+    /// `prices` and NaN-padded before the first full window.
+    pub fn standard_deviation_series(&self, prices: &[f64], period: usize) -> Result<Vec<f64>> {
+        if period == 0 {
+            return Err(anyhow::anyhow!("Standard deviation period must be greater than zero"));
+        }
+
+        Ok(rolling::run_sd(prices, period))
     }
 
     /// Volume Indicators
@@ -183,13 +549,24 @@ impl TechnicalIndicators {
     }
 
     pub fn accumulation_distribution_line(&self, highs: &[f64], lows: &[f64], closes: &[f64], volumes: &[f64]) -> Result<Vec<f64>> {
-        // TODO: Calculate Accumulation/Distribution Line
-        // - Calculate Money Flow Multiplier: ((Close-Low) - (High-Close)) / (High-Low)
-        // - Calculate Money Flow Volume: Multiplier * Volume
-        // - Calculate cumulative A/D Line by adding Money Flow Volume
-        // - Handle edge case where High == Low
-        // - Return cumulative A/D line series
-        panic!("TODO: Implement Accumulation/Distribution Line calculation")
+        if highs.len() != lows.len() || highs.len() != closes.len() || highs.len() != volumes.len() {
+            return Err(anyhow::anyhow!("highs/lows/closes/volumes must have equal length"));
+        }
+
+        let mut line = Vec::with_capacity(closes.len());
+        let mut cumulative = 0.0;
+        for i in 0..closes.len() {
+            let range = highs[i] - lows[i];
+            let money_flow_multiplier = if range > 0.0 {
+                ((closes[i] - lows[i]) - (highs[i] - closes[i])) / range
+            } else {
+                0.0
+            };
+            cumulative += money_flow_multiplier * volumes[i];
+            line.push(cumulative);
+        }
+
+        Ok(line)
     }
 
     pub fn money_flow_index(&self, highs: &[f64], lows: &[f64], closes: &[f64], volumes: &[f64], period: usize) -> Result<f64> {
@@ -212,6 +589,108 @@ impl TechnicalIndicators {
         panic!("TODO: Implement VWAP calculation")
     }
 
+    /// Chaikin Oscillator: `EMA(AD_line, fast_period) - EMA(AD_line, slow_period)`.
+    /// Both periods are supplied by the caller; there are no defaults (TTR's
+    /// conventional fast/slow are 3 and 10).
+    pub fn chaikin_oscillator(&self, highs: &[f64], lows: &[f64], closes: &[f64], volumes: &[f64], fast_period: usize, slow_period: usize) -> Result<Vec<f64>> {
+        let ad_line = self.accumulation_distribution_line(highs, lows, closes, volumes)?;
+        let fast_ema = self.ema_series(&ad_line, fast_period)?;
+        let slow_ema = self.ema_series(&ad_line, slow_period)?;
+
+        Ok(fast_ema
+            .iter()
+            .zip(slow_ema.iter())
+            .map(|(fast, slow)| if fast.is_nan() || slow.is_nan() { f64::NAN } else { fast - slow })
+            .collect())
+    }
+
+    /// Chaikin Money Flow: sum of Money Flow Volume over `period` divided
+    /// by sum of volume over `period`, aligned with `closes`.
+    pub fn chaikin_money_flow(&self, highs: &[f64], lows: &[f64], closes: &[f64], volumes: &[f64], period: usize) -> Result<Vec<f64>> {
+        if highs.len() != lows.len() || highs.len() != closes.len() || highs.len() != volumes.len() {
+            return Err(anyhow::anyhow!("highs/lows/closes/volumes must have equal length"));
+        }
+        if period == 0 {
+            return Err(anyhow::anyhow!("Chaikin Money Flow period must be greater than zero"));
+        }
+
+        let money_flow_volume: Vec<f64> = (0..closes.len())
+            .map(|i| {
+                let range = highs[i] - lows[i];
+                let multiplier = if range > 0.0 {
+                    ((closes[i] - lows[i]) - (highs[i] - closes[i])) / range
+                } else {
+                    0.0
+                };
+                multiplier * volumes[i]
+            })
+            .collect();
+
+        let mfv_sum = rolling::run_sum(&money_flow_volume, period);
+        let volume_sum = rolling::run_sum(volumes, period);
+
+        Ok(mfv_sum
+            .iter()
+            .zip(volume_sum.iter())
+            .map(|(mfv, vol)| if mfv.is_nan() || vol.is_nan() || *vol == 0.0 { f64::NAN } else { mfv / vol })
+            .collect())
+    }
+
+    /// Awesome Oscillator: `SMA(median_price, 5) - SMA(median_price, 34)`.
+    pub fn awesome_oscillator(&self, highs: &[f64], lows: &[f64]) -> Result<Vec<f64>> {
+        if highs.len() != lows.len() {
+            return Err(anyhow::anyhow!("highs/lows must have equal length"));
+        }
+
+        let median_prices: Vec<f64> = highs
+            .iter()
+            .zip(lows.iter())
+            .map(|(h, l)| self.median_price(*h, *l))
+            .collect();
+
+        let fast = self.sma_series(&median_prices, 5)?;
+        let slow = self.sma_series(&median_prices, 34)?;
+
+        Ok(fast
+            .iter()
+            .zip(slow.iter())
+            .map(|(f, s)| if f.is_nan() || s.is_nan() { f64::NAN } else { f - s })
+            .collect())
+    }
+
+    /// Aroon Up/Down over `period`: `100*(period - bars_since_extreme)/period`,
+    /// where AroonUp tracks bars since the highest high and AroonDown
+    /// tracks bars since the lowest low, both within the trailing window.
+    pub fn aroon(&self, highs: &[f64], lows: &[f64], period: usize) -> Result<(Vec<f64>, Vec<f64>)> {
+        if highs.len() != lows.len() {
+            return Err(anyhow::anyhow!("highs/lows must have equal length"));
+        }
+        if period == 0 {
+            return Err(anyhow::anyhow!("Aroon period must be greater than zero"));
+        }
+
+        let len = highs.len();
+        let mut aroon_up = vec![f64::NAN; len];
+        let mut aroon_down = vec![f64::NAN; len];
+        if len < period + 1 {
+            return Ok((aroon_up, aroon_down));
+        }
+
+        // Aroon's window is `period + 1` bars: the current bar plus the
+        // preceding `period`.
+        let high_idx = rolling::run_argmax(highs, period + 1);
+        let low_idx = rolling::run_argmin(lows, period + 1);
+
+        for i in period..len {
+            let bars_since_high = i - high_idx[i].unwrap();
+            let bars_since_low = i - low_idx[i].unwrap();
+            aroon_up[i] = 100.0 * (period as f64 - bars_since_high as f64) / period as f64;
+            aroon_down[i] = 100.0 * (period as f64 - bars_since_low as f64) / period as f64;
+        }
+
+        Ok((aroon_up, aroon_down))
+    }
+
     /// Trend Indicators
     pub fn parabolic_sar(&self, highs: &[f64], lows: &[f64], acceleration_factor: f64, max_acceleration: f64) -> Result<Vec<f64>> {
         // TODO: Calculate Parabolic SAR (Stop and Reverse)
@@ -238,13 +717,35 @@ impl TechnicalIndicators {
 
     /// Oscillators
     pub fn williams_percent_r(&self, highs: &[f64], lows: &[f64], closes: &[f64], period: usize) -> Result<f64> {
-        // TODO: Calculate Williams %R
-        // - Find highest high over period
-        // - Find lowest low over period
-        // - Calculate %R: ((Highest High - Close) / (Highest High - Lowest Low)) * -100
-        // - Handle edge case where highest high == lowest low
-        // - Return %R value (range: -100 to 0)
-        panic!("TODO: Implement Williams %R calculation")
+        last_valid(&self.williams_percent_r_series(highs, lows, closes, period)?)
+            .ok_or_else(|| anyhow::anyhow!("Not enough data for Williams %R period {}", period))
+    }
+
+    /// Williams %R (`(highest_high - close) / (highest_high - lowest_low) * -100`
+    /// over `period`), aligned with `closes`. Range: -100 to 0.
+    pub fn williams_percent_r_series(&self, highs: &[f64], lows: &[f64], closes: &[f64], period: usize) -> Result<Vec<f64>> {
+        if highs.len() != lows.len() || highs.len() != closes.len() {
+            return Err(anyhow::anyhow!("highs/lows/closes must have equal length"));
+        }
+        if period == 0 {
+            return Err(anyhow::anyhow!("Williams %R period must be greater than zero"));
+        }
+
+        let len = closes.len();
+        let mut series = vec![f64::NAN; len];
+        if len < period {
+            return Ok(series);
+        }
+
+        for i in (period - 1)..len {
+            let window_start = i + 1 - period;
+            let highest = highs[window_start..=i].iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+            let lowest = lows[window_start..=i].iter().cloned().fold(f64::INFINITY, f64::min);
+            let range = highest - lowest;
+            series[i] = if range > 0.0 { (highest - closes[i]) / range * -100.0 } else { -50.0 };
+        }
+
+        Ok(series)
     }
 
     pub fn rate_of_change(&self, prices: &[f64], period: usize) -> Result<f64> {
@@ -310,28 +811,17 @@ impl TechnicalIndicators {
 
     /// Utility Functions
     pub fn true_range(&self, high: f64, low: f64, prev_close: f64) -> f64 {
-        // TODO: Calculate True Range for single period
-        // - Calculate three possible ranges:
-        //   1. high - low
-        //   2. abs(high - prev_close)
-        //   3. abs(low - prev_close)
-        // - Return maximum of the three values
-        panic!("TODO: Implement True Range calculation")
+        (high - low)
+            .max((high - prev_close).abs())
+            .max((low - prev_close).abs())
     }
 
     pub fn typical_price(&self, high: f64, low: f64, close: f64) -> f64 {
-        // TODO: Calculate Typical Price (HLC average)
-        // - Simple calculation: (high + low + close) / 3
-        // - Used in many volume-based indicators
-        // - Handle edge cases gracefully
-        panic!("TODO: Implement Typical Price calculation")
+        (high + low + close) / 3.0
     }
 
     pub fn median_price(&self, high: f64, low: f64) -> f64 {
-        // TODO: Calculate Median Price (HL average)
-        // - Simple calculation: (high + low) / 2
-        // - Used in some price-based calculations
-        panic!("TODO: Implement Median Price calculation")
+        (high + low) / 2.0
     }
 
     /// Advanced Indicators
@@ -355,6 +845,69 @@ impl TechnicalIndicators {
         panic!("TODO: Implement Elder Ray Index calculation")
     }
 
+    /// Price Adjustment
+    ///
+    /// Split and dividend adjustment ratios for back-adjusting a raw OHLC
+    /// series, per TTR's `adjRatios`. Without this, RSI/MACD/moving averages
+    /// computed on raw prices show spurious jumps around corporate-action
+    /// dates. `splits`/`dividends` are `(bar_index, factor)` pairs: a split
+    /// factor of e.g. `0.5` means a 2-for-1 split on that bar, and a
+    /// dividend is the cash amount paid on that bar.
+    ///
+    /// Returns `(split_ratios, dividend_ratios)`, each the same length as
+    /// `closes`. The split ratio at a bar is the cumulative product (walked
+    /// backward from the end) of split factors strictly after that bar; the
+    /// dividend ratio is the cumulative product of `(1 - dividend_i /
+    /// close_{i-1})` terms, also walked backward.
+    pub fn adj_ratios(&self, splits: &[(usize, f64)], dividends: &[(usize, f64)], closes: &[f64]) -> Result<(Vec<f64>, Vec<f64>)> {
+        if closes.is_empty() {
+            return Err(anyhow::anyhow!("closes must not be empty"));
+        }
+
+        let mut split_factor_at = vec![1.0; closes.len()];
+        for &(index, factor) in splits {
+            if index >= closes.len() {
+                return Err(anyhow::anyhow!("split index {} out of range for {} closes", index, closes.len()));
+            }
+            split_factor_at[index] *= factor;
+        }
+
+        let mut split_ratios = vec![1.0; closes.len()];
+        for i in (0..closes.len() - 1).rev() {
+            split_ratios[i] = split_ratios[i + 1] * split_factor_at[i + 1];
+        }
+
+        let mut dividend_factor_at = vec![1.0; closes.len()];
+        for &(index, amount) in dividends {
+            if index == 0 || index >= closes.len() {
+                return Err(anyhow::anyhow!("dividend index {} out of range for {} closes", index, closes.len()));
+            }
+            dividend_factor_at[index] *= 1.0 - amount / closes[index - 1];
+        }
+
+        let mut dividend_ratios = vec![1.0; closes.len()];
+        for i in (0..closes.len() - 1).rev() {
+            dividend_ratios[i] = dividend_ratios[i + 1] * dividend_factor_at[i + 1];
+        }
+
+        Ok((split_ratios, dividend_ratios))
+    }
+
+    /// Back-adjusts `prices` by the combined split/dividend ratio, as
+    /// computed by `adj_ratios`.
+    pub fn adjust_prices(&self, prices: &[f64], split_ratios: &[f64], dividend_ratios: &[f64]) -> Result<Vec<f64>> {
+        if prices.len() != split_ratios.len() || prices.len() != dividend_ratios.len() {
+            return Err(anyhow::anyhow!("prices/split_ratios/dividend_ratios must have equal length"));
+        }
+
+        Ok(prices
+            .iter()
+            .zip(split_ratios.iter())
+            .zip(dividend_ratios.iter())
+            .map(|((price, split), dividend)| price * split * dividend)
+            .collect())
+    }
+
     /// Performance Optimization Helpers
     pub fn rolling_calculation<F>(&self, data: &[f64], window: usize, calc_fn: F) -> Result<Vec<f64>>
     where
@@ -368,45 +921,420 @@ impl TechnicalIndicators {
         panic!("TODO: Implement generic rolling calculation framework")
     }
 
+    /// Fold one new price into a streaming indicator's state in O(1),
+    /// instead of recomputing the indicator over the whole history.
     pub fn streaming_update<T>(&self, indicator_state: &mut T, new_price: f64) -> Result<f64>
     where
-        T: TechnicalIndicatorState,
+        T: TechnicalIndicatorState<Input = f64, Output = f64>,
     {
-        // TODO: Update indicator state with new price for streaming
-        // - Maintain internal state for real-time updates
-        // - Avoid recalculating entire series for each update
-        // - Handle state initialization and management
-        // - Return updated indicator value
-        panic!("TODO: Implement streaming indicator updates")
+        Ok(indicator_state.next(new_price))
     }
 }
 
+/// Trait for incremental indicator state: each call to `next` folds in one
+/// new data point and returns the updated value, instead of recomputing the
+/// indicator over the whole history. Modeled on the OHLCV-driven streaming
+/// design of crates like yata. Implementors that derive `Serialize`/
+/// `Deserialize` can have their state persisted and resumed across restarts.
 pub trait TechnicalIndicatorState {
-    // TODO: Define trait for streaming indicator state management
-    // - Methods for updating state with new data
-    // - Methods for retrieving current indicator value
-    // - State serialization/deserialization for persistence
-    // - Memory management for fixed-size rolling windows
+    type Input;
+    type Output;
+
+    /// Fold `input` into the indicator's state and return its updated value.
+    fn next(&mut self, input: Self::Input) -> Self::Output;
+
+    /// The indicator's last computed value, or `None` if `next` hasn't been
+    /// called enough times yet to produce one.
+    fn current(&self) -> Option<Self::Output>;
 }
 
-/// Streaming indicator implementations for real-time updates
+/// Streaming Simple Moving Average: a fixed-capacity circular buffer plus a
+/// running sum, so each update is O(1) instead of re-summing the window.
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StreamingSMA {
-    // TODO: Implement streaming SMA state
-    // - Maintain rolling sum and count
-    // - Use circular buffer for efficiency
-    // - Handle window size management
+    window: VecDeque<f64>,
+    capacity: usize,
+    sum: f64,
+}
+
+impl StreamingSMA {
+    pub fn new(period: usize) -> Self {
+        Self {
+            window: VecDeque::with_capacity(period.max(1)),
+            capacity: period.max(1),
+            sum: 0.0,
+        }
+    }
+}
+
+impl TechnicalIndicatorState for StreamingSMA {
+    type Input = f64;
+    type Output = f64;
+
+    fn next(&mut self, input: f64) -> f64 {
+        self.window.push_back(input);
+        self.sum += input;
+
+        if self.window.len() > self.capacity {
+            if let Some(oldest) = self.window.pop_front() {
+                self.sum -= oldest;
+            }
+        }
+
+        self.sum / self.window.len() as f64
+    }
+
+    fn current(&self) -> Option<f64> {
+        if self.window.is_empty() {
+            None
+        } else {
+            Some(self.sum / self.window.len() as f64)
+        }
+    }
 }
 
+/// Streaming Exponential Moving Average: `ema = α*price + (1-α)*prev`,
+/// seeded from the first price observed.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 pub struct StreamingEMA {
-    // TODO: Implement streaming EMA state
-    // - Maintain current EMA value
-    // - Store smoothing factor
-    // - Handle initialization properly
+    alpha: f64,
+    prev: Option<f64>,
+}
+
+impl StreamingEMA {
+    pub fn new(period: usize) -> Self {
+        Self {
+            alpha: 2.0 / (period as f64 + 1.0),
+            prev: None,
+        }
+    }
+}
+
+impl TechnicalIndicatorState for StreamingEMA {
+    type Input = f64;
+    type Output = f64;
+
+    fn next(&mut self, input: f64) -> f64 {
+        let ema = match self.prev {
+            Some(prev) => self.alpha * input + (1.0 - self.alpha) * prev,
+            None => input,
+        };
+        self.prev = Some(ema);
+        ema
+    }
+
+    fn current(&self) -> Option<f64> {
+        self.prev
+    }
 }
 
+/// Streaming Wilder-smoothed RSI: simple averages of gain/loss over the
+/// first `period` price changes seed the calculation, then each subsequent
+/// change is folded in with Wilder's recursive smoothing.
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StreamingRSI {
-    // TODO: Implement streaming RSI state
-    // - Maintain average gain and loss
-    // - Use Wilder's smoothing method
-    // - Handle edge cases in real-time
+    period: usize,
+    prev_price: Option<f64>,
+    seed_gains: Vec<f64>,
+    seed_losses: Vec<f64>,
+    seeded: bool,
+    avg_gain: f64,
+    avg_loss: f64,
+    current: Option<f64>,
+}
+
+impl StreamingRSI {
+    pub fn new(period: usize) -> Self {
+        Self {
+            period: period.max(1),
+            prev_price: None,
+            seed_gains: Vec::new(),
+            seed_losses: Vec::new(),
+            seeded: false,
+            avg_gain: 0.0,
+            avg_loss: 0.0,
+            current: None,
+        }
+    }
+
+}
+
+impl TechnicalIndicatorState for StreamingRSI {
+    type Input = f64;
+    type Output = f64;
+
+    fn next(&mut self, input: f64) -> f64 {
+        let prev_price = match self.prev_price.replace(input) {
+            Some(prev) => prev,
+            None => return self.current.unwrap_or(50.0),
+        };
+
+        let change = input - prev_price;
+        let gain = change.max(0.0);
+        let loss = (-change).max(0.0);
+
+        if !self.seeded {
+            self.seed_gains.push(gain);
+            self.seed_losses.push(loss);
+
+            if self.seed_gains.len() == self.period {
+                self.avg_gain = self.seed_gains.iter().sum::<f64>() / self.period as f64;
+                self.avg_loss = self.seed_losses.iter().sum::<f64>() / self.period as f64;
+                self.seeded = true;
+                self.current = Some(rsi_from_averages(self.avg_gain, self.avg_loss));
+            }
+        } else {
+            let period = self.period as f64;
+            self.avg_gain = (self.avg_gain * (period - 1.0) + gain) / period;
+            self.avg_loss = (self.avg_loss * (period - 1.0) + loss) / period;
+            self.current = Some(rsi_from_averages(self.avg_gain, self.avg_loss));
+        }
+
+        self.current.unwrap_or(50.0)
+    }
+
+    fn current(&self) -> Option<f64> {
+        self.current
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_streaming_sma_matches_batch_average() {
+        let mut sma = StreamingSMA::new(3);
+        assert_eq!(sma.next(10.0), 10.0);
+        assert_eq!(sma.next(20.0), 15.0);
+        assert_eq!(sma.next(30.0), 20.0);
+        // Window slides: oldest (10.0) drops out.
+        assert_eq!(sma.next(60.0), (20.0 + 30.0 + 60.0) / 3.0);
+        assert_eq!(sma.current(), Some((20.0 + 30.0 + 60.0) / 3.0));
+    }
+
+    #[test]
+    fn test_streaming_ema_seeds_from_first_price() {
+        let mut ema = StreamingEMA::new(3);
+        assert_eq!(ema.next(10.0), 10.0);
+        let alpha = 2.0 / 4.0;
+        let expected = alpha * 20.0 + (1.0 - alpha) * 10.0;
+        assert!((ema.next(20.0) - expected).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_streaming_rsi_is_100_with_no_losses() {
+        let mut rsi = StreamingRSI::new(3);
+        rsi.next(10.0);
+        rsi.next(11.0);
+        rsi.next(12.0);
+        let value = rsi.next(13.0);
+        assert_eq!(value, 100.0);
+    }
+
+    #[test]
+    fn test_streaming_rsi_matches_wilder_smoothing_after_seed() {
+        let mut rsi = StreamingRSI::new(2);
+        rsi.next(10.0); // seeds prev_price, no change yet
+        rsi.next(12.0); // +2
+        let seeded = rsi.next(11.0); // -1, completes the 2-change seed window
+        let expected_avg_gain = 2.0 / 2.0;
+        let expected_avg_loss = 1.0 / 2.0;
+        let expected_rsi = 100.0 - 100.0 / (1.0 + expected_avg_gain / expected_avg_loss);
+        assert!((seeded - expected_rsi).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_sma_series_is_nan_padded_then_matches_simple_moving_average() {
+        let indicators = TechnicalIndicators::new();
+        let prices = vec![10.0, 11.0, 12.0, 13.0, 14.0];
+        let series = indicators.sma_series(&prices, 3).unwrap();
+        assert!(series[0].is_nan());
+        assert!(series[1].is_nan());
+        let expected = indicators.simple_moving_average(&prices, 3).unwrap();
+        assert!((series[4] - expected).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_ema_series_seeds_with_sma_then_recurses() {
+        let indicators = TechnicalIndicators::new();
+        let prices = vec![10.0, 12.0, 14.0, 9.0];
+        let series = indicators.ema_series(&prices, 2).unwrap();
+        assert!(series[0].is_nan());
+        let seed = (10.0 + 12.0) / 2.0;
+        assert!((series[1] - seed).abs() < 1e-12);
+        let alpha = 2.0 / 3.0;
+        let expected = alpha * 14.0 + (1.0 - alpha) * seed;
+        assert!((series[2] - expected).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_rsi_series_matches_relative_strength_index() {
+        let indicators = TechnicalIndicators::new();
+        let prices = vec![44.0, 44.5, 43.5, 45.0, 46.0, 45.5, 47.0, 46.5];
+        let series = indicators.rsi_series(&prices, 3).unwrap();
+        let single = indicators.relative_strength_index(&prices, 3).unwrap();
+        assert!((last_valid(&series).unwrap() - single).abs() < 1e-12);
+        assert!(series[0].is_nan());
+    }
+
+    #[test]
+    fn test_macd_histogram_is_macd_minus_signal() {
+        let indicators = TechnicalIndicators::new();
+        let prices: Vec<f64> = (0..30).map(|i| 100.0 + i as f64 * 0.5).collect();
+        let (macd_line, signal_line, histogram) = indicators.macd_series(&prices, 3, 6, 3).unwrap();
+        let last = prices.len() - 1;
+        assert!((histogram[last] - (macd_line[last] - signal_line[last])).abs() < 1e-9);
+        let (macd, signal, hist) = indicators.macd(&prices, 3, 6, 3).unwrap();
+        assert!((hist - (macd - signal)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_macd_rejects_fast_period_not_less_than_slow() {
+        let indicators = TechnicalIndicators::new();
+        let prices = vec![1.0, 2.0, 3.0, 4.0, 5.0];
+        assert!(indicators.macd(&prices, 5, 5, 3).is_err());
+    }
+
+    #[test]
+    fn test_stochastic_oscillator_at_highest_high_is_100() {
+        let indicators = TechnicalIndicators::new();
+        let highs = vec![10.0, 11.0, 12.0];
+        let lows = vec![8.0, 9.0, 10.0];
+        let closes = vec![9.0, 10.0, 12.0];
+        let (k, _) = indicators.stochastic_oscillator(&highs, &lows, &closes, 3, 1).unwrap();
+        assert!((k - 100.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_williams_percent_r_at_lowest_low_is_minus_100() {
+        let indicators = TechnicalIndicators::new();
+        let highs = vec![10.0, 11.0, 12.0];
+        let lows = vec![8.0, 9.0, 7.0];
+        let closes = vec![9.0, 10.0, 7.0];
+        let r = indicators.williams_percent_r(&highs, &lows, &closes, 3).unwrap();
+        assert!((r - (-100.0)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_wma_series_weights_most_recent_bar_highest() {
+        let indicators = TechnicalIndicators::new();
+        let prices = vec![10.0, 20.0];
+        let series = indicators.wma_series(&prices, 2).unwrap();
+        // weights 1, 2 -> (10*1 + 20*2) / 3
+        assert!((series[1] - (10.0 + 40.0) / 3.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_wilder_series_matches_hand_rolled_smoothing() {
+        let indicators = TechnicalIndicators::new();
+        let prices = vec![10.0, 20.0, 15.0];
+        let series = indicators.wilder_series(&prices, 2).unwrap();
+        let seed = (10.0 + 20.0) / 2.0;
+        assert!((series[1] - seed).abs() < 1e-12);
+        let expected = seed + (15.0 - seed) / 2.0;
+        assert!((series[2] - expected).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_bollinger_bands_with_ma_matches_selected_middle_band() {
+        let indicators = TechnicalIndicators::new();
+        let prices: Vec<f64> = (0..10).map(|i| 100.0 + i as f64).collect();
+        let (_, middle, _) = indicators.bollinger_bands_with_ma(&prices, 3, 2.0, MovingAverageType::Exponential).unwrap();
+        let expected_middle = indicators.exponential_moving_average(&prices, 3).unwrap();
+        assert!((middle - expected_middle).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_atr_with_ma_simple_matches_plain_sma_of_true_ranges() {
+        let indicators = TechnicalIndicators::new();
+        let highs = vec![10.0, 11.0, 12.0, 13.0];
+        let lows = vec![9.0, 9.5, 10.5, 11.5];
+        let closes = vec![9.5, 10.5, 11.5, 12.5];
+        let atr = indicators.atr_with_ma(&highs, &lows, &closes, 2, MovingAverageType::Simple).unwrap();
+        let true_ranges: Vec<f64> = (0..highs.len())
+            .map(|i| if i == 0 { highs[i] - lows[i] } else { indicators.true_range(highs[i], lows[i], closes[i - 1]) })
+            .collect();
+        let expected = indicators.simple_moving_average(&true_ranges, 2).unwrap();
+        assert!((atr - expected).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_adj_ratios_applies_split_before_the_split_date() {
+        let indicators = TechnicalIndicators::new();
+        let closes = vec![100.0, 100.0, 50.0, 52.0];
+        // 2-for-1 split on bar 2 halves the pre-split price.
+        let (split_ratios, dividend_ratios) = indicators.adj_ratios(&[(2, 0.5)], &[], &closes).unwrap();
+        assert_eq!(split_ratios, vec![0.5, 0.5, 1.0, 1.0]);
+        assert_eq!(dividend_ratios, vec![1.0, 1.0, 1.0, 1.0]);
+
+        let adjusted = indicators.adjust_prices(&closes, &split_ratios, &dividend_ratios).unwrap();
+        assert_eq!(adjusted, vec![50.0, 50.0, 50.0, 52.0]);
+    }
+
+    #[test]
+    fn test_adj_ratios_applies_dividend_before_the_ex_date() {
+        let indicators = TechnicalIndicators::new();
+        let closes = vec![100.0, 98.0, 99.0];
+        // $2 dividend paid on bar 1, against the prior close of 100.
+        let (_, dividend_ratios) = indicators.adj_ratios(&[], &[(1, 2.0)], &closes).unwrap();
+        assert!((dividend_ratios[0] - 0.98).abs() < 1e-12);
+        assert_eq!(dividend_ratios[1], 1.0);
+        assert_eq!(dividend_ratios[2], 1.0);
+    }
+
+    #[test]
+    fn test_adj_ratios_rejects_out_of_range_index() {
+        let indicators = TechnicalIndicators::new();
+        let closes = vec![100.0, 101.0];
+        assert!(indicators.adj_ratios(&[(5, 2.0)], &[], &closes).is_err());
+    }
+
+    #[test]
+    fn test_accumulation_distribution_line_accumulates_money_flow_volume() {
+        let indicators = TechnicalIndicators::new();
+        let highs = vec![10.0, 12.0];
+        let lows = vec![8.0, 9.0];
+        let closes = vec![9.0, 12.0];
+        let volumes = vec![100.0, 200.0];
+        let ad = indicators.accumulation_distribution_line(&highs, &lows, &closes, &volumes).unwrap();
+        // bar 0: mult = ((9-8)-(10-9))/(10-8) = 0 -> no change
+        assert_eq!(ad[0], 0.0);
+        // bar 1: mult = ((12-9)-(12-12))/(12-9) = 1 -> +200
+        assert_eq!(ad[1], 200.0);
+    }
+
+    #[test]
+    fn test_chaikin_money_flow_is_bounded_between_minus_one_and_one() {
+        let indicators = TechnicalIndicators::new();
+        let highs = vec![10.0, 12.0, 11.0, 13.0];
+        let lows = vec![8.0, 9.0, 9.5, 10.0];
+        let closes = vec![9.5, 11.5, 10.0, 12.5];
+        let volumes = vec![100.0, 150.0, 120.0, 200.0];
+        let cmf = indicators.chaikin_money_flow(&highs, &lows, &closes, &volumes, 2).unwrap();
+        for value in cmf.iter().filter(|v| !v.is_nan()) {
+            assert!(*value >= -1.0 && *value <= 1.0);
+        }
+    }
+
+    #[test]
+    fn test_awesome_oscillator_is_zero_for_flat_median_price() {
+        let indicators = TechnicalIndicators::new();
+        let highs = vec![10.0; 40];
+        let lows = vec![8.0; 40];
+        let ao = indicators.awesome_oscillator(&highs, &lows).unwrap();
+        let last = ao.len() - 1;
+        assert!((ao[last] - 0.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_aroon_up_is_100_when_high_is_the_most_recent_bar() {
+        let indicators = TechnicalIndicators::new();
+        let highs = vec![10.0, 11.0, 12.0, 20.0];
+        let lows = vec![8.0, 9.0, 10.0, 15.0];
+        let (aroon_up, aroon_down) = indicators.aroon(&highs, &lows, 3).unwrap();
+        let last = highs.len() - 1;
+        assert!((aroon_up[last] - 100.0).abs() < 1e-9);
+        assert!(aroon_down[last] < 100.0);
+    }
 }
\ No newline at end of file
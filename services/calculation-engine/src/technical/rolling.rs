@@ -0,0 +1,236 @@
+//! Fast windowed statistics, aligned with their input and NaN-padded before
+//! the first full window - the same convention `*_series` methods on
+//! `TechnicalIndicators` use. Unlike `rolling_calculation`, which re-invokes
+//! a closure over each window slice (`O(n*window)`), every primitive here is
+//! `O(n)` total.
+
+use std::collections::VecDeque;
+
+/// Running sum over a sliding window, updated by `+new - old` per step.
+pub fn run_sum(data: &[f64], window: usize) -> Vec<f64> {
+    let mut series = vec![f64::NAN; data.len()];
+    if window == 0 || data.len() < window {
+        return series;
+    }
+
+    let mut sum: f64 = data[0..window].iter().sum();
+    series[window - 1] = sum;
+    for i in window..data.len() {
+        sum += data[i] - data[i - window];
+        series[i] = sum;
+    }
+
+    series
+}
+
+/// Running mean over a sliding window, derived from `run_sum`.
+pub fn run_mean(data: &[f64], window: usize) -> Vec<f64> {
+    run_sum(data, window)
+        .into_iter()
+        .map(|sum| sum / window as f64)
+        .collect()
+}
+
+/// Running max over a sliding window via a monotonic deque of indices:
+/// pop from the back while the incoming value dominates, pop from the
+/// front once the window slides past the oldest retained index. Amortized
+/// `O(1)` per step.
+pub fn run_max(data: &[f64], window: usize) -> Vec<f64> {
+    run_extremum_index(data, window, |a, b| a >= b)
+        .into_iter()
+        .map(|idx| idx.map_or(f64::NAN, |i| data[i]))
+        .collect()
+}
+
+/// Running min over a sliding window, the mirror image of `run_max`.
+pub fn run_min(data: &[f64], window: usize) -> Vec<f64> {
+    run_extremum_index(data, window, |a, b| a <= b)
+        .into_iter()
+        .map(|idx| idx.map_or(f64::NAN, |i| data[i]))
+        .collect()
+}
+
+/// Index (into `data`) of the running max over a sliding window, `None`
+/// before the first full window. The same monotonic-deque front as
+/// `run_max`, exposed for callers that need bars-since-the-extremum
+/// (e.g. `TechnicalIndicators::aroon`) rather than just its value.
+pub fn run_argmax(data: &[f64], window: usize) -> Vec<Option<usize>> {
+    run_extremum_index(data, window, |a, b| a >= b)
+}
+
+/// Index (into `data`) of the running min over a sliding window, the
+/// mirror image of `run_argmax`.
+pub fn run_argmin(data: &[f64], window: usize) -> Vec<Option<usize>> {
+    run_extremum_index(data, window, |a, b| a <= b)
+}
+
+fn run_extremum_index(data: &[f64], window: usize, dominates: impl Fn(f64, f64) -> bool) -> Vec<Option<usize>> {
+    let mut series = vec![None; data.len()];
+    if window == 0 || data.len() < window {
+        return series;
+    }
+
+    let mut deque: VecDeque<usize> = VecDeque::new();
+    for i in 0..data.len() {
+        while let Some(&back) = deque.back() {
+            if dominates(data[i], data[back]) {
+                deque.pop_back();
+            } else {
+                break;
+            }
+        }
+        deque.push_back(i);
+
+        if let Some(&front) = deque.front() {
+            if front + window <= i {
+                deque.pop_front();
+            }
+        }
+
+        if i + 1 >= window {
+            series[i] = deque.front().copied();
+        }
+    }
+
+    series
+}
+
+/// Running population variance over a sliding window via Welford's online
+/// algorithm adapted for a window: maintains running mean and `M2`, and
+/// when the oldest element `x_old` leaves a window of size `n`, applies the
+/// reverse update `mean' = (n*mean - x_old)/(n-1)` before folding `x_old`
+/// back out of `M2`. Numerically stable without re-summing squared
+/// deviations on every step.
+pub fn run_var(data: &[f64], window: usize) -> Vec<f64> {
+    let mut series = vec![f64::NAN; data.len()];
+    if window == 0 || data.len() < window {
+        return series;
+    }
+    if window == 1 {
+        // A single-element window always has zero variance; the general
+        // recurrence below divides by `window - 1` and would produce NaN.
+        for i in 0..data.len() {
+            series[i] = 0.0;
+        }
+        return series;
+    }
+
+    let mut mean = 0.0;
+    let mut m2 = 0.0;
+    for (n, &x) in data[0..window].iter().enumerate() {
+        let n = n + 1;
+        let delta = x - mean;
+        mean += delta / n as f64;
+        m2 += delta * (x - mean);
+    }
+    series[window - 1] = m2 / window as f64;
+
+    for i in window..data.len() {
+        let x_old = data[i - window];
+        let n = window as f64;
+        let old_mean = mean;
+        mean = (n * mean - x_old) / (n - 1.0);
+        m2 -= (x_old - old_mean) * (x_old - mean);
+
+        let x_new = data[i];
+        let new_mean = mean + (x_new - mean) / n;
+        m2 += (x_new - mean) * (x_new - new_mean);
+        mean = new_mean;
+
+        series[i] = m2 / window as f64;
+    }
+
+    series
+}
+
+/// Running population standard deviation, derived from `run_var`.
+pub fn run_sd(data: &[f64], window: usize) -> Vec<f64> {
+    run_var(data, window).into_iter().map(f64::sqrt).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_run_sum_matches_naive_windowed_sum() {
+        let data = vec![1.0, 2.0, 3.0, 4.0, 5.0];
+        let series = run_sum(&data, 3);
+        assert!(series[0].is_nan());
+        assert!(series[1].is_nan());
+        assert_eq!(series[2], 6.0);
+        assert_eq!(series[3], 9.0);
+        assert_eq!(series[4], 12.0);
+    }
+
+    #[test]
+    fn test_run_mean_matches_run_sum_divided_by_window() {
+        let data = vec![2.0, 4.0, 6.0, 8.0];
+        let series = run_mean(&data, 2);
+        assert_eq!(series[1], 3.0);
+        assert_eq!(series[2], 5.0);
+        assert_eq!(series[3], 7.0);
+    }
+
+    #[test]
+    fn test_run_max_and_run_min_track_sliding_window_extremes() {
+        let data = vec![3.0, 1.0, 4.0, 1.0, 5.0, 9.0];
+        let max_series = run_max(&data, 3);
+        let min_series = run_min(&data, 3);
+        assert_eq!(max_series[2], 4.0); // window [3,1,4]
+        assert_eq!(max_series[3], 4.0); // window [1,4,1]
+        assert_eq!(max_series[4], 5.0); // window [4,1,5]
+        assert_eq!(max_series[5], 9.0); // window [1,5,9]
+        assert_eq!(min_series[2], 1.0);
+        assert_eq!(min_series[5], 1.0); // window [1,5,9]
+    }
+
+    #[test]
+    fn test_run_argmax_and_run_argmin_track_extremum_index() {
+        let data = vec![3.0, 1.0, 4.0, 1.0, 5.0, 9.0];
+        let argmax = run_argmax(&data, 3);
+        let argmin = run_argmin(&data, 3);
+        assert_eq!(argmax[1], None); // window not yet full
+        assert_eq!(argmax[2], Some(2)); // window [3,1,4] -> 4 at index 2
+        assert_eq!(argmax[4], Some(4)); // window [4,1,5] -> 5 at index 4
+        assert_eq!(argmax[5], Some(5)); // window [1,5,9] -> 9 at index 5
+        assert_eq!(argmin[2], Some(1)); // window [3,1,4] -> 1 at index 1
+        assert_eq!(argmin[5], Some(3)); // window [1,5,9] -> 1 at index 3
+    }
+
+    #[test]
+    fn test_run_var_matches_naive_population_variance() {
+        let data = vec![2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0];
+        let window = 4;
+        let series = run_var(&data, window);
+
+        for i in (window - 1)..data.len() {
+            let slice = &data[(i + 1 - window)..=i];
+            let mean = slice.iter().sum::<f64>() / window as f64;
+            let expected = slice.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / window as f64;
+            assert!((series[i] - expected).abs() < 1e-9, "index {}: {} vs {}", i, series[i], expected);
+        }
+    }
+
+    #[test]
+    fn test_run_var_window_of_one_is_always_zero_not_nan() {
+        let data = vec![1.0, 5.0, -3.0, 9.0];
+        let series = run_var(&data, 1);
+        assert_eq!(series, vec![0.0, 0.0, 0.0, 0.0]);
+        assert!(run_sd(&data, 1).iter().all(|v| *v == 0.0));
+    }
+
+    #[test]
+    fn test_run_sd_is_sqrt_of_run_var() {
+        let data = vec![1.0, 5.0, 3.0, 9.0, 2.0];
+        let var_series = run_var(&data, 3);
+        let sd_series = run_sd(&data, 3);
+        for (var, sd) in var_series.into_iter().zip(sd_series.into_iter()) {
+            if var.is_nan() {
+                assert!(sd.is_nan());
+            } else {
+                assert!((sd - var.sqrt()).abs() < 1e-12);
+            }
+        }
+    }
+}
@@ -0,0 +1,332 @@
+use anyhow::Result;
+use std::collections::HashMap;
+use std::io::{BufRead, Write};
+
+use crate::cache_optimized::{CacheOptimizedMarketData, CacheOptimizedPriceArray};
+
+/// A CSV row that failed to parse, kept around for reporting instead of
+/// aborting the whole ingest.
+#[derive(Debug, Clone)]
+pub struct MalformedRow {
+    pub line_number: usize,
+    pub raw: String,
+    pub reason: String,
+}
+
+/// Outcome of a streaming ingest: how many rows made it into the SoA arrays
+/// versus how many were skipped, with a reason for each skip.
+#[derive(Debug, Clone, Default)]
+pub struct IngestSummary {
+    pub rows_ingested: usize,
+    pub rows_skipped: usize,
+    pub malformed: Vec<MalformedRow>,
+}
+
+/// Running per-symbol aggregates maintained on-the-fly during ingest:
+/// min/max/mean price, total volume, and VWAP.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SymbolAggregate {
+    pub min_price: f64,
+    pub max_price: f64,
+    sum_price: f64,
+    count: u64,
+    pub total_volume: u64,
+    weighted_sum: f64,
+}
+
+impl SymbolAggregate {
+    fn update(&mut self, price: f64, volume: u64) {
+        if self.count == 0 {
+            self.min_price = price;
+            self.max_price = price;
+        } else {
+            self.min_price = self.min_price.min(price);
+            self.max_price = self.max_price.max(price);
+        }
+        self.sum_price += price;
+        self.count += 1;
+        self.total_volume += volume;
+        self.weighted_sum += price * volume as f64;
+    }
+
+    pub fn mean_price(&self) -> f64 {
+        if self.count == 0 { 0.0 } else { self.sum_price / self.count as f64 }
+    }
+
+    pub fn vwap(&self) -> f64 {
+        if self.total_volume == 0 { 0.0 } else { self.weighted_sum / self.total_volume as f64 }
+    }
+}
+
+/// Byte-level parser for an unsigned/float decimal field, avoiding the
+/// UTF-8 validation and formatting-flag overhead of `str::parse`. Accepts an
+/// optional leading `-` and an optional `.` fractional part.
+fn parse_fast_f64(field: &[u8]) -> Option<f64> {
+    if field.is_empty() {
+        return None;
+    }
+
+    let mut idx = 0;
+    let negative = field[0] == b'-';
+    if negative {
+        idx += 1;
+    }
+    if idx >= field.len() {
+        return None;
+    }
+
+    let mut int_part: u64 = 0;
+    let mut saw_digit = false;
+    while idx < field.len() && field[idx].is_ascii_digit() {
+        int_part = int_part * 10 + (field[idx] - b'0') as u64;
+        idx += 1;
+        saw_digit = true;
+    }
+
+    let mut frac_part: f64 = 0.0;
+    if idx < field.len() && field[idx] == b'.' {
+        idx += 1;
+        let mut scale = 0.1;
+        while idx < field.len() && field[idx].is_ascii_digit() {
+            frac_part += (field[idx] - b'0') as f64 * scale;
+            scale *= 0.1;
+            idx += 1;
+            saw_digit = true;
+        }
+    }
+
+    if !saw_digit || idx != field.len() {
+        return None;
+    }
+
+    let value = int_part as f64 + frac_part;
+    Some(if negative { -value } else { value })
+}
+
+/// Byte-level parser for an unsigned integer field (volume, timestamp).
+fn parse_fast_u64(field: &[u8]) -> Option<u64> {
+    if field.is_empty() {
+        return None;
+    }
+    let mut value: u64 = 0;
+    for &b in field {
+        if !b.is_ascii_digit() {
+            return None;
+        }
+        value = value * 10 + (b - b'0') as u64;
+    }
+    Some(value)
+}
+
+/// One parsed tick record: symbol, price, volume, timestamp.
+struct TickRecord<'a> {
+    symbol: &'a str,
+    price: f64,
+    volume: u64,
+    #[allow(dead_code)]
+    timestamp: u64,
+}
+
+/// Parse a single `symbol,price,volume,timestamp` CSV line using byte
+/// slices only - no intermediate `Vec`/struct allocation beyond the final
+/// borrowed fields.
+fn parse_tick_line(line: &str) -> std::result::Result<TickRecord<'_>, &'static str> {
+    let mut fields = line.split(',');
+
+    let symbol = fields.next().ok_or("missing symbol field")?.trim();
+    if symbol.is_empty() {
+        return Err("empty symbol field");
+    }
+
+    let price = fields.next().ok_or("missing price field")?.trim();
+    let price = parse_fast_f64(price.as_bytes()).ok_or("unparseable price field")?;
+
+    let volume = fields.next().ok_or("missing volume field")?.trim();
+    let volume = parse_fast_u64(volume.as_bytes()).ok_or("unparseable volume field")?;
+
+    let timestamp = fields.next().ok_or("missing timestamp field")?.trim();
+    let timestamp = parse_fast_u64(timestamp.as_bytes()).ok_or("unparseable timestamp field")?;
+
+    if fields.next().is_some() {
+        return Err("too many fields");
+    }
+
+    Ok(TickRecord { symbol, price, volume, timestamp })
+}
+
+/// Stream a `symbol,price,volume,timestamp` tick CSV directly into one
+/// `CacheOptimizedPriceArray` per symbol, allocating each array lazily with
+/// `capacity_per_symbol` slots the first time its symbol is seen. Malformed
+/// rows are skipped and reported in the returned `IngestSummary` rather than
+/// aborting the stream.
+pub fn ingest_csv<R: BufRead>(
+    reader: R,
+    arrays: &mut HashMap<String, CacheOptimizedPriceArray>,
+    capacity_per_symbol: usize,
+) -> Result<IngestSummary> {
+    let mut summary = IngestSummary::default();
+
+    for (line_number, line) in reader.lines().enumerate() {
+        let line = line?;
+        if line.is_empty() {
+            continue;
+        }
+
+        match parse_tick_line(&line) {
+            Ok(record) => {
+                let array = arrays
+                    .entry(record.symbol.to_string())
+                    .or_insert_with(|| CacheOptimizedPriceArray::new(capacity_per_symbol));
+
+                if array.len() >= array.capacity() {
+                    summary.rows_skipped += 1;
+                    summary.malformed.push(MalformedRow {
+                        line_number,
+                        raw: line,
+                        reason: "symbol array at capacity".to_string(),
+                    });
+                    continue;
+                }
+
+                let data = CacheOptimizedMarketData::new(record.symbol, record.price, record.volume);
+                array.push(&data);
+                summary.rows_ingested += 1;
+            }
+            Err(reason) => {
+                summary.rows_skipped += 1;
+                summary.malformed.push(MalformedRow {
+                    line_number,
+                    raw: line,
+                    reason: reason.to_string(),
+                });
+            }
+        }
+    }
+
+    Ok(summary)
+}
+
+/// Same streaming ingest as `ingest_csv`, but also maintains running
+/// per-symbol aggregates (min/max/mean price, total volume, VWAP) and writes
+/// a summary row to `summary_out` after every row that updates them, giving
+/// a live on-the-fly query mode alongside the bulk population of the arrays.
+pub fn ingest_csv_with_query<R: BufRead, W: Write>(
+    reader: R,
+    arrays: &mut HashMap<String, CacheOptimizedPriceArray>,
+    capacity_per_symbol: usize,
+    mut summary_out: W,
+) -> Result<(IngestSummary, HashMap<String, SymbolAggregate>)> {
+    let mut summary = IngestSummary::default();
+    let mut aggregates: HashMap<String, SymbolAggregate> = HashMap::new();
+
+    for (line_number, line) in reader.lines().enumerate() {
+        let line = line?;
+        if line.is_empty() {
+            continue;
+        }
+
+        match parse_tick_line(&line) {
+            Ok(record) => {
+                let array = arrays
+                    .entry(record.symbol.to_string())
+                    .or_insert_with(|| CacheOptimizedPriceArray::new(capacity_per_symbol));
+
+                if array.len() >= array.capacity() {
+                    summary.rows_skipped += 1;
+                    summary.malformed.push(MalformedRow {
+                        line_number,
+                        raw: line,
+                        reason: "symbol array at capacity".to_string(),
+                    });
+                    continue;
+                }
+
+                let data = CacheOptimizedMarketData::new(record.symbol, record.price, record.volume);
+                array.push(&data);
+                summary.rows_ingested += 1;
+
+                let aggregate = aggregates.entry(record.symbol.to_string()).or_default();
+                aggregate.update(record.price, record.volume);
+
+                writeln!(
+                    summary_out,
+                    "{},{:.4},{:.4},{:.4},{},{:.4}",
+                    record.symbol,
+                    aggregate.min_price,
+                    aggregate.max_price,
+                    aggregate.mean_price(),
+                    aggregate.total_volume,
+                    aggregate.vwap()
+                )?;
+            }
+            Err(reason) => {
+                summary.rows_skipped += 1;
+                summary.malformed.push(MalformedRow {
+                    line_number,
+                    raw: line,
+                    reason: reason.to_string(),
+                });
+            }
+        }
+    }
+
+    Ok((summary, aggregates))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    #[test]
+    fn test_parse_fast_f64() {
+        assert_eq!(parse_fast_f64(b"123.456"), Some(123.456));
+        assert_eq!(parse_fast_f64(b"-12.5"), Some(-12.5));
+        assert_eq!(parse_fast_f64(b"100"), Some(100.0));
+        assert_eq!(parse_fast_f64(b""), None);
+        assert_eq!(parse_fast_f64(b"12a"), None);
+    }
+
+    #[test]
+    fn test_ingest_csv_populates_arrays() {
+        let csv = "AAPL,150.0,1000,1\nAAPL,151.5,2000,2\nMSFT,300.0,500,3\n";
+        let mut arrays = HashMap::new();
+
+        let summary = ingest_csv(Cursor::new(csv), &mut arrays, 10).unwrap();
+
+        assert_eq!(summary.rows_ingested, 3);
+        assert_eq!(summary.rows_skipped, 0);
+        assert_eq!(arrays["AAPL"].len(), 2);
+        assert_eq!(arrays["MSFT"].len(), 1);
+    }
+
+    #[test]
+    fn test_ingest_csv_skips_malformed_rows() {
+        let csv = "AAPL,150.0,1000,1\nBADROW\nMSFT,not_a_price,500,3\n";
+        let mut arrays = HashMap::new();
+
+        let summary = ingest_csv(Cursor::new(csv), &mut arrays, 10).unwrap();
+
+        assert_eq!(summary.rows_ingested, 1);
+        assert_eq!(summary.rows_skipped, 2);
+        assert_eq!(summary.malformed.len(), 2);
+    }
+
+    #[test]
+    fn test_ingest_csv_with_query_tracks_vwap() {
+        let csv = "AAPL,100.0,100,1\nAAPL,200.0,100,2\n";
+        let mut arrays = HashMap::new();
+        let mut out = Vec::new();
+
+        let (summary, aggregates) = ingest_csv_with_query(Cursor::new(csv), &mut arrays, 10, &mut out).unwrap();
+
+        assert_eq!(summary.rows_ingested, 2);
+        let aapl = aggregates["AAPL"];
+        assert_eq!(aapl.min_price, 100.0);
+        assert_eq!(aapl.max_price, 200.0);
+        assert!((aapl.vwap() - 150.0).abs() < 1e-9);
+
+        let output = String::from_utf8(out).unwrap();
+        assert_eq!(output.lines().count(), 2);
+    }
+}